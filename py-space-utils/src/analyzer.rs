@@ -1,74 +1,761 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use globset::GlobSet;
+use log::{debug, trace, warn};
+use rayon::{ThreadPool, ThreadPoolBuilder, prelude::*};
 
 use crate::{
-    assignments::fix_assignments, errors::py_space::PySpaceError, file_utils::get_files_iterator,
-    models::statement_value::StatementValue, parse_statement::extract_file_data,
+    assignments::{fix_assignments, fix_attributes},
+    class_hierarchy::{ClassHierarchy, fix_inheritance},
+    config_file::read_console_scripts,
+    data_model::is_dunder,
+    errors::py_space::PySpaceError,
+    file_utils::{
+        build_glob_set, collect_python_files, get_module_name, get_project_root, get_relative_path,
+        get_root_paths, is_package_file,
+    },
+    models::{
+        argument_def::ArgumentDef,
+        class_def::ClassDef,
+        config::{ArgumentExemptions, Config},
+        finding::Finding,
+        finding_kind::FindingKind,
+        function_def::FunctionDef,
+        graph::{Graph, GraphNode, GraphNodeKind},
+        report::Report,
+        statement_value::StatementValue,
+        variable_def::VariableDef,
+    },
+    parse_statement::extract_file_data,
     possible_functions::handle_possible_functions,
+    reachability::{Reachability, is_root},
+    resolve_names::resolve_names,
+    unused_imports::mark_used_imports,
 };
 
-pub fn analyze_project() -> Result<bool, PySpaceError> {
-    let mut statement_value = StatementValue::new();
-    let mut unused_function = HashSet::new();
+const UNUSED_FUNCTION_CONFIDENCE: u8 = 60;
+const UNUSED_CLASS_CONFIDENCE: u8 = 60;
+const UNUSED_IMPORT_CONFIDENCE: u8 = 90;
+const UNUSED_VARIABLE_CONFIDENCE: u8 = 60;
+const UNUSED_ARGUMENT_CONFIDENCE: u8 = 80;
+const UNUSED_LOCAL_VARIABLE_CONFIDENCE: u8 = 80;
+const UNREACHABLE_CODE_CONFIDENCE: u8 = 90;
+const DEAD_CODE_CONFIDENCE: u8 = 50;
+const IGNORED_VARIABLE: &str = "_";
+const SELF_ARGUMENTS: [&str; 2] = ["self", "cls"];
 
-    let walker = get_files_iterator()?;
+fn analyze_file(path: &Path, roots: &[PathBuf]) -> Result<StatementValue, PySpaceError> {
+    let statement_value = extract_file_data(path.to_path_buf())?;
+    let statement_value = mark_used_imports(statement_value, is_package_file(path));
+    let statement_value = fix_assignments(statement_value);
 
-    for file_result in walker {
-        let file = match file_result {
-            Ok(value) => value,
-            Err(error) => {
-                dbg!("error parsing file: {}", error);
+    let module_name = get_module_name(path, roots);
 
-                continue;
-            }
+    Ok(resolve_names(
+        statement_value,
+        &module_name,
+        is_package_file(path),
+    ))
+}
+
+fn build_thread_pool(config: &Config) -> Result<ThreadPool, PySpaceError> {
+    let mut builder = ThreadPoolBuilder::new();
+
+    if let Some(jobs) = config.jobs {
+        builder = builder.num_threads(jobs);
+    }
+
+    match builder.build() {
+        Ok(value) => Ok(value),
+        Err(error) => Err(PySpaceError::FailedToBuildThreadPool(error.to_string())),
+    }
+}
+
+fn analyze_files(
+    paths: &[PathBuf],
+    roots: &[PathBuf],
+    project_root: &Path,
+    thread_pool: &ThreadPool,
+) -> Vec<Result<StatementValue, PySpaceError>> {
+    thread_pool.install(|| {
+        paths
+            .par_iter()
+            .map(|path| {
+                let file_start_time = Instant::now();
+                let result = analyze_file(path, roots);
+
+                trace!(
+                    "parsed {} in {:?}",
+                    get_relative_path(path, project_root).display(),
+                    file_start_time.elapsed()
+                );
+
+                result
+            })
+            .collect()
+    })
+}
+
+fn is_kept(name: &str, config: &Config, whitelist: &Option<GlobSet>) -> bool {
+    if config
+        .entry_points
+        .iter()
+        .any(|entry_point| entry_point == name)
+    {
+        trace!("keeping entry point {}", name);
+
+        return true;
+    }
+
+    if whitelist
+        .as_ref()
+        .is_some_and(|glob_set| glob_set.is_match(name))
+    {
+        trace!("keeping whitelisted {}", name);
+
+        return true;
+    }
+
+    false
+}
+
+fn find_unused_functions(
+    statement_value: &StatementValue,
+    reachability: &Reachability,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut reported_names = HashSet::new();
+
+    // A function defined several times, a property setter or a `def` in both
+    // branches of an `if`, is reported once, at its first definition.
+    let mut functions: Vec<&FunctionDef> = statement_value.functions.iter().collect();
+    functions
+        .sort_by(|left, right| (&left.file, left.location).cmp(&(&right.file, right.location)));
+
+    for function in functions {
+        let name = function.qualified_name();
+
+        if statement_value
+            .expression_value
+            .used_functions
+            .contains(&name)
+        {
+            continue;
+        }
+
+        if is_kept(&name, config, whitelist) || is_root(function) {
+            continue;
+        }
+
+        // Python calls dunder methods on its own, given the syntax for them
+        // and an instance of a class that is used.
+        if function.class_name.is_some()
+            && is_dunder(&function.name)
+            && reachability.is_reachable(&name)
+        {
+            continue;
+        }
+
+        if !reported_names.insert(name.clone()) {
+            continue;
+        }
+
+        let (kind, reason) = match function.class_name {
+            Some(_) => (FindingKind::UnusedMethod, "method is never called"),
+            None => (FindingKind::UnusedFunction, "function is never called"),
         };
 
-        let path = file.path();
-        let path_str = path.to_str().map_or("", |value| value);
-        let is_venv = path_str.contains("venv");
-        let is_python_file = path
-            .extension()
-            .map_or(false, |extension| extension == "py");
+        findings.push(Finding::new(
+            kind,
+            name,
+            function.file.clone(),
+            function.location,
+            function.end_location,
+            UNUSED_FUNCTION_CONFIDENCE,
+            reason.to_string(),
+        ));
+    }
+
+    findings
+}
+
+fn find_unused_classes(
+    statement_value: &StatementValue,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut reported_names = HashSet::new();
+    let expression_value = &statement_value.expression_value;
+
+    // A class defined in both branches of an `if` or a `try` is reported
+    // once, at its first definition.
+    let mut classes: Vec<&ClassDef> = statement_value.classes.iter().collect();
+    classes.sort_by(|left, right| (&left.file, left.location).cmp(&(&right.file, right.location)));
+
+    for class in classes {
+        let name = class.qualified_name();
+
+        // Instantiating, subclassing, annotating with or passing the class to
+        // isinstance all leave its resolved name in one of these sets.
+        let is_used = expression_value.used_names.contains(&name)
+            || expression_value.used_functions.contains(&name)
+            || expression_value.used_attributes.contains(&name);
 
-        if !is_python_file || is_venv {
+        if is_used || is_kept(&name, config, whitelist) || !reported_names.insert(name.clone()) {
             continue;
         }
 
-        let new_statement_value = match extract_file_data(path.to_path_buf()) {
-            Ok(value) => value,
-            Err(error) => {
-                dbg!("error getting file data: {}", error);
+        findings.push(Finding::new(
+            FindingKind::UnusedClass,
+            name,
+            class.file.clone(),
+            class.location,
+            class.end_location,
+            UNUSED_CLASS_CONFIDENCE,
+            "class is never used".to_string(),
+        ));
+    }
+
+    findings
+}
+
+fn find_unused_imports(
+    statement_value: &StatementValue,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for file_data in statement_value.files.values() {
+        for imports in file_data.imports.values() {
+            for import_data in imports {
+                let name = import_data.qualified_name();
+
+                if import_data.is_used || is_kept(&name, config, whitelist) {
+                    continue;
+                }
+
+                let reason = match import_data.is_type_checking {
+                    true => "type-checking import is never used in an annotation",
+                    false => "import is never used",
+                };
+
+                findings.push(Finding::new(
+                    FindingKind::UnusedImport,
+                    name,
+                    file_data.path.clone(),
+                    import_data.location,
+                    import_data.end_location,
+                    UNUSED_IMPORT_CONFIDENCE,
+                    reason.to_string(),
+                ));
+            }
+        }
+    }
+
+    findings
+}
+
+fn is_exported(statement_value: &StatementValue, variable: &VariableDef) -> bool {
+    variable.class_name.is_none()
+        && statement_value
+            .files
+            .get(&variable.file)
+            .is_some_and(|file_data| file_data.exported_names.contains(&variable.name))
+}
+
+/// Every used name plus its dotted prefixes, since reading `config.debug` or
+/// calling `client.send()` also reads `config` and `client`.
+fn build_read_names(statement_value: &StatementValue) -> HashSet<&str> {
+    let expression_value = &statement_value.expression_value;
+    let mut read_names = HashSet::new();
+
+    let used_names = expression_value
+        .used_names
+        .iter()
+        .chain(&expression_value.used_attributes)
+        .chain(&expression_value.used_functions);
+
+    for name in used_names {
+        read_names.insert(name.as_str());
+
+        for (index, _) in name.match_indices(".") {
+            read_names.insert(&name[..index]);
+        }
+    }
+
+    read_names
+}
+
+fn find_unused_variables(
+    statement_value: &StatementValue,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut reported_names = HashSet::new();
+    let read_names = build_read_names(statement_value);
+
+    // A variable assigned several times is reported once, at its first
+    // assignment.
+    let mut variables: Vec<&VariableDef> = statement_value.variables.iter().collect();
+    variables
+        .sort_by(|left, right| (&left.file, left.location).cmp(&(&right.file, right.location)));
+
+    for variable in variables {
+        if variable.name == IGNORED_VARIABLE || is_dunder(&variable.name) {
+            continue;
+        }
+
+        let name = variable.qualified_name();
+
+        let is_read = read_names.contains(name.as_str()) || is_exported(statement_value, variable);
+
+        if is_read || is_kept(&name, config, whitelist) || !reported_names.insert(name.clone()) {
+            continue;
+        }
+
+        let reason = match variable.class_name {
+            Some(_) => "class variable is never read",
+            None => "module variable is never read",
+        };
+
+        findings.push(Finding::new(
+            FindingKind::UnusedVariable,
+            name,
+            variable.file.clone(),
+            variable.location,
+            variable.end_location,
+            UNUSED_VARIABLE_CONFIDENCE,
+            reason.to_string(),
+        ));
+    }
+
+    findings
+}
+
+fn find_unused_locals(
+    statement_value: &StatementValue,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
 
+    for function in &statement_value.functions {
+        for variable in &function.unused_locals {
+            let name = format!("{}.{}", function.qualified_name(), variable.name);
+
+            if is_kept(&name, config, whitelist) {
                 continue;
             }
+
+            findings.push(Finding::new(
+                FindingKind::UnusedVariable,
+                name,
+                function.file.clone(),
+                variable.location,
+                variable.end_location,
+                UNUSED_LOCAL_VARIABLE_CONFIDENCE,
+                "local variable is never read".to_string(),
+            ));
+        }
+    }
+
+    findings
+}
+
+fn is_argument_exempt(
+    function: &FunctionDef,
+    argument: &ArgumentDef,
+    hierarchy: &ClassHierarchy,
+    exemptions: &ArgumentExemptions,
+) -> bool {
+    if exemptions.self_and_cls
+        && function.class_name.is_some()
+        && SELF_ARGUMENTS.contains(&argument.name.as_str())
+    {
+        return true;
+    }
+
+    if exemptions.underscore_prefixed && argument.name.starts_with("_") {
+        return true;
+    }
+
+    if exemptions.stub_bodies && function.is_stub {
+        return true;
+    }
+
+    // Python fixes the signature of dunder methods, as if each overrode one
+    // on `object`, or on the module for `__getattr__` and `__dir__`.
+    if exemptions.overridden_methods && is_dunder(&function.name) {
+        return true;
+    }
+
+    // An override has to accept whatever its base is called with, and a
+    // base has to accept whatever its overrides need.
+    if exemptions.overridden_methods
+        && let Some(class_name) = &function.class_name
+    {
+        let class_name = match function.module.is_empty() {
+            true => class_name.clone(),
+            false => format!("{}.{}", function.module, class_name),
         };
 
-        statement_value.merge_statement_value(new_statement_value);
+        return hierarchy.is_override(&class_name, &function.name)
+            || hierarchy.is_overridden(&class_name, &function.name);
     }
 
-    let statement_value = fix_assignments(statement_value);
-    let statement_value = handle_possible_functions(statement_value);
+    false
+}
+
+fn find_unused_arguments(
+    statement_value: &StatementValue,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let hierarchy = ClassHierarchy::new(statement_value);
 
     for function in &statement_value.functions {
-        let name = function.full_name();
-        let path = function.file.to_str().map_or("", |value| value);
+        for argument in &function.unused_arguments {
+            let name = format!("{}.{}", function.qualified_name(), argument.name);
 
-        if !statement_value
-            .expression_value
-            .used_functions
-            .contains(&name)
-        {
-            unused_function.insert((name, path));
+            if is_argument_exempt(function, argument, &hierarchy, &config.argument_exemptions)
+                || is_kept(&name, config, whitelist)
+            {
+                continue;
+            }
+
+            findings.push(Finding::new(
+                FindingKind::UnusedArgument,
+                name,
+                function.file.clone(),
+                argument.location,
+                argument.end_location,
+                UNUSED_ARGUMENT_CONFIDENCE,
+                "argument is never read".to_string(),
+            ));
+        }
+    }
+
+    findings
+}
+
+fn find_unreachable_code(
+    statement_value: &StatementValue,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for unreachable_code in &statement_value.unreachable_code {
+        let name = unreachable_code.qualified_name();
+
+        if is_kept(&name, config, whitelist) {
+            continue;
+        }
+
+        findings.push(Finding::new(
+            FindingKind::UnreachableCode,
+            name,
+            unreachable_code.file.clone(),
+            unreachable_code.location,
+            unreachable_code.end_location,
+            UNREACHABLE_CODE_CONFIDENCE,
+            unreachable_code.reason.clone(),
+        ));
+    }
+
+    findings
+}
+
+fn build_roots(
+    statement_value: &StatementValue,
+    config: &Config,
+    whitelist: &Option<GlobSet>,
+) -> Vec<String> {
+    let mut roots = config.entry_points.clone();
+
+    for function in &statement_value.functions {
+        let name = function.qualified_name();
+
+        if is_root(function) || is_kept(&name, config, whitelist) {
+            roots.push(name);
+        }
+    }
+
+    for class in &statement_value.classes {
+        let name = class.qualified_name();
+
+        if is_kept(&name, config, whitelist) {
+            roots.push(name);
         }
     }
 
-    let mut result_string = String::from("");
+    roots
+}
+
+fn get_dead_code_reason(starts: &[String]) -> String {
+    if starts.is_empty() {
+        return "only used from a cycle of code that never runs".to_string();
+    }
+
+    format!(
+        "only used from code that never runs, starting at {}",
+        starts.join(", ")
+    )
+}
+
+fn find_dead_code(statement_value: &StatementValue, reachability: &Reachability) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let expression_value = &statement_value.expression_value;
 
-    for value in unused_function {
-        result_string += &format!("{}: {}\n", value.0, value.1);
+    let is_used = |name: &str| {
+        expression_value.used_functions.contains(name)
+            || expression_value.used_names.contains(name)
+            || expression_value.used_attributes.contains(name)
+    };
+
+    // Code that is never used at all is reported as unused already, only
+    // code that is used, but never from anything that runs, is dead.
+    for function in &statement_value.functions {
+        let name = function.qualified_name();
+
+        if reachability.is_reachable(&name) || !expression_value.used_functions.contains(&name) {
+            continue;
+        }
+
+        findings.push(Finding::new(
+            FindingKind::DeadCode,
+            name.clone(),
+            function.file.clone(),
+            function.location,
+            function.end_location,
+            DEAD_CODE_CONFIDENCE,
+            get_dead_code_reason(&reachability.dead_starts(&name)),
+        ));
+    }
+
+    for class in &statement_value.classes {
+        let name = class.qualified_name();
+
+        if reachability.is_reachable(&name) || !is_used(&name) {
+            continue;
+        }
+
+        findings.push(Finding::new(
+            FindingKind::DeadCode,
+            name.clone(),
+            class.file.clone(),
+            class.location,
+            class.end_location,
+            DEAD_CODE_CONFIDENCE,
+            get_dead_code_reason(&reachability.dead_starts(&name)),
+        ));
+    }
+
+    findings
+}
+
+fn is_suppressed(statement_value: &StatementValue, finding: &Finding) -> bool {
+    statement_value
+        .files
+        .get(&finding.file)
+        .is_some_and(|file_data| file_data.is_suppressed(finding.line, finding.kind))
+}
+
+/// Parses every file of the project into one StatementValue, counting the
+/// files scanned and the ones that failed in `report`.
+fn parse_project(
+    config: &Config,
+    project_root: &Path,
+    report: &mut Report,
+) -> Result<StatementValue, PySpaceError> {
+    let start_time = Instant::now();
+    let mut statement_value = StatementValue::new();
+
+    let thread_pool = build_thread_pool(config)?;
+    let paths = collect_python_files(config, project_root)?;
+    let roots = get_root_paths(config)?;
+
+    debug!(
+        "parsing {} files on {} threads",
+        paths.len(),
+        thread_pool.current_num_threads()
+    );
+
+    // Results come back in the order of `paths`, which is sorted, so merging
+    // them one by one gives the same StatementValue for any number of threads.
+    for result in analyze_files(&paths, &roots, project_root, &thread_pool) {
+        report.files_scanned += 1;
+
+        match result {
+            Ok(value) => statement_value.merge_statement_value(value),
+            Err(error) => {
+                warn!("skipping file: {}", error);
+                report.files_failed += 1;
+            }
+        }
+    }
+
+    if report.files_scanned > 0 && report.files_scanned == report.files_failed {
+        return Err(PySpaceError::AllFilesFailedToParse(report.files_failed));
+    }
+
+    debug!(
+        "parsed {} files ({} failed) in {:?}",
+        report.files_scanned,
+        report.files_failed,
+        start_time.elapsed()
+    );
+
+    let statement_value = fix_attributes(statement_value);
+    let statement_value = handle_possible_functions(statement_value);
+
+    Ok(fix_inheritance(statement_value))
+}
+
+pub fn analyze_project(config: &Config) -> Result<Report, PySpaceError> {
+    let start_time = Instant::now();
+    let mut report = Report::new();
+
+    let project_root = get_project_root(config)?;
+    let statement_value = parse_project(config, &project_root, &mut report)?;
+    let whitelist = build_glob_set(&config.whitelist)?;
+
+    // Functions console scripts start from are kept like entry points.
+    let mut config = config.clone();
+    config
+        .entry_points
+        .extend(read_console_scripts(&project_root)?);
+    let config = &config;
+
+    let roots = build_roots(&statement_value, config, &whitelist);
+    let reachability = Reachability::new(&statement_value, roots);
+
+    report.findings.extend(find_unused_functions(
+        &statement_value,
+        &reachability,
+        config,
+        &whitelist,
+    ));
+
+    report
+        .findings
+        .extend(find_unused_classes(&statement_value, config, &whitelist));
+
+    report
+        .findings
+        .extend(find_unused_imports(&statement_value, config, &whitelist));
+
+    report
+        .findings
+        .extend(find_unused_variables(&statement_value, config, &whitelist));
+
+    report
+        .findings
+        .extend(find_unused_locals(&statement_value, config, &whitelist));
+
+    report
+        .findings
+        .extend(find_unused_arguments(&statement_value, config, &whitelist));
+
+    report
+        .findings
+        .extend(find_unreachable_code(&statement_value, config, &whitelist));
+
+    report
+        .findings
+        .extend(find_dead_code(&statement_value, &reachability));
+
+    report.findings.retain(|finding| {
+        config.is_kind_enabled(finding.kind)
+            && finding.confidence >= config.min_confidence
+            && !is_suppressed(&statement_value, finding)
+    });
+
+    for finding in &mut report.findings {
+        finding.file = get_relative_path(&finding.file, &project_root).to_path_buf();
+    }
+
+    report.sort_findings();
+    report.duration = start_time.elapsed();
+
+    debug!(
+        "found {} unused symbols in {:?}",
+        report.findings.len(),
+        report.duration
+    );
+
+    Ok(report)
+}
+
+/// Builds the call graph of the project: its modules, classes and functions,
+/// with an edge from each one to every node called in its body.
+pub fn analyze_call_graph(config: &Config) -> Result<Graph, PySpaceError> {
+    let mut report = Report::new();
+    let mut graph = Graph::new();
+
+    let project_root = get_project_root(config)?;
+    let statement_value = parse_project(config, &project_root, &mut report)?;
+    let roots = get_root_paths(config)?;
+
+    for path in statement_value.files.keys() {
+        graph.insert_node(
+            get_module_name(path, &roots),
+            GraphNode {
+                kind: GraphNodeKind::Module,
+                file: get_relative_path(path, &project_root).to_path_buf(),
+                line: 1,
+            },
+        );
+    }
+
+    for class in &statement_value.classes {
+        graph.insert_node(
+            class.qualified_name(),
+            GraphNode {
+                kind: GraphNodeKind::Class,
+                file: get_relative_path(&class.file, &project_root).to_path_buf(),
+                line: class.location.line,
+            },
+        );
+    }
+
+    for function in &statement_value.functions {
+        let kind = match function.class_name {
+            Some(_) => GraphNodeKind::Method,
+            None => GraphNodeKind::Function,
+        };
+
+        graph.insert_node(
+            function.qualified_name(),
+            GraphNode {
+                kind,
+                file: get_relative_path(&function.file, &project_root).to_path_buf(),
+                line: function.location.line,
+            },
+        );
+    }
+
+    for (caller, callees) in &statement_value.call_graph.calls {
+        for callee in callees {
+            graph.insert_edge(caller, callee);
+        }
     }
 
-    print!("{}", result_string);
-    dbg!(statement_value.expression_value.possible_functions);
+    debug!(
+        "built a call graph of {} nodes and {} edges",
+        graph.nodes.len(),
+        graph.edges.len()
+    );
 
-    Ok(true)
+    Ok(graph)
 }
@@ -1,66 +1,148 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::models::statement_value::StatementValue;
+use log::trace;
 
-const INIT_FUNCTIONS: [&str; 2] = ["__init__", "__new__"];
+use crate::models::{statement_value::StatementValue, trigger::Receiver};
 
-pub fn fix_assignments(mut statement_value: StatementValue) -> StatementValue {
+fn resolve_assigned_calls(
+    functions: &HashSet<String>,
+    assignments: &HashMap<String, String>,
+) -> HashSet<String> {
     let mut used_functions = HashSet::new();
-    let old_used_functions = statement_value.expression_value.used_functions.clone();
 
-    for function in old_used_functions {
+    for function in functions {
         if !function.contains(".") {
-            used_functions.insert(function);
+            used_functions.insert(function.clone());
             continue;
         }
 
         let mut parts = function.split(".");
 
         if parts.clone().count() != 2 {
-            dbg!(&parts);
+            trace!("keeping nested call {} as is", function);
 
-            used_functions.insert(function);
+            used_functions.insert(function.clone());
             continue;
         }
 
         let mut class_name = parts.next().unwrap().to_string();
         let function_name = parts.next().unwrap();
 
-        if !statement_value.assignments.contains_key(&class_name) {
+        if !assignments.contains_key(&class_name) {
             used_functions.insert(format!("{}.{}", class_name, function_name));
 
             continue;
         }
 
-        class_name = statement_value
-            .assignments
-            .get(&class_name)
-            .unwrap()
-            .to_string();
+        class_name = assignments.get(&class_name).unwrap().to_string();
+
+        trace!("resolved {} to {}.{}", function, class_name, function_name);
+
+        used_functions.insert(format!("{}.{}", class_name, function_name));
+    }
 
-        for init_function in INIT_FUNCTIONS {
-            let name = format!("{}.{}", class_name, init_function);
+    used_functions
+}
 
-            if !used_functions.contains(&name) {
-                used_functions.insert(name);
+/// Makes `x` in `x = Foo(); x()` an instance of `Foo`, and `x.save` the
+/// `Foo.save` it is bound to.
+fn resolve_assigned_receiver(
+    receiver: Receiver,
+    assignments: &HashMap<String, String>,
+) -> Receiver {
+    let Receiver::Name(name) = &receiver else {
+        return receiver;
+    };
+
+    match name.split_once(".") {
+        None => match assignments.get(name) {
+            Some(class_name) => Receiver::Instance(class_name.clone()),
+            None => receiver,
+        },
+        Some((instance_name, attribute_name)) if !attribute_name.contains(".") => {
+            match assignments.get(instance_name) {
+                Some(class_name) => Receiver::Name(format!("{}.{}", class_name, attribute_name)),
+                None => receiver,
             }
         }
-
-        used_functions.insert(format!("{}.{}", class_name, function_name));
+        Some(_) => receiver,
     }
+}
 
-    let functions_name = statement_value.build_full_name_functions();
+pub fn fix_assignments(mut statement_value: StatementValue) -> StatementValue {
+    let used_functions = resolve_assigned_calls(
+        &statement_value.expression_value.used_functions,
+        &statement_value.assignments,
+    );
+
+    statement_value.expression_value.used_functions = used_functions;
+
+    let mut used_attributes = statement_value.expression_value.used_attributes.clone();
 
     for attribute in &statement_value.expression_value.used_attributes {
+        let Some((instance_name, attribute_name)) = attribute.split_once(".") else {
+            continue;
+        };
+
+        if attribute_name.contains(".") {
+            continue;
+        }
+
+        if let Some(class_name) = statement_value.assignments.get(instance_name) {
+            trace!(
+                "resolved {} to {}.{}",
+                attribute, class_name, attribute_name
+            );
+
+            used_attributes.insert(format!("{}.{}", class_name, attribute_name));
+        }
+    }
+
+    statement_value.expression_value.used_attributes = used_attributes;
+
+    let triggers = std::mem::take(&mut statement_value.expression_value.triggers);
+
+    statement_value.expression_value.triggers = triggers
+        .into_iter()
+        .map(|(trigger, receiver)| {
+            (
+                trigger,
+                resolve_assigned_receiver(receiver, &statement_value.assignments),
+            )
+        })
+        .collect();
+
+    // Names used inside each function go through the same resolution, keeping
+    // the originals since they mix calls with plain reads.
+    let call_graph = &mut statement_value.call_graph;
+
+    for names in call_graph.edges.values_mut() {
+        let resolved_names = resolve_assigned_calls(names, &statement_value.assignments);
+
+        names.extend(resolved_names);
+    }
+
+    for names in call_graph.calls.values_mut() {
+        *names = resolve_assigned_calls(names, &statement_value.assignments);
+    }
+
+    statement_value
+}
+
+pub fn fix_attributes(mut statement_value: StatementValue) -> StatementValue {
+    let functions_name = statement_value.build_qualified_name_functions();
+    let expression_value = &mut statement_value.expression_value;
+
+    for attribute in &expression_value.used_attributes {
         let attribute_is_function = functions_name.contains(attribute);
-        let attribute_seen_already = used_functions.contains(attribute);
+        let attribute_seen_already = expression_value.used_functions.contains(attribute);
 
         if attribute_is_function && !attribute_seen_already {
-            used_functions.insert(attribute.to_string());
+            expression_value
+                .used_functions
+                .insert(attribute.to_string());
         }
     }
 
-    statement_value.expression_value.used_functions = used_functions;
-
     statement_value
 }
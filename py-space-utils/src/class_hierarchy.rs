@@ -0,0 +1,436 @@
+use std::collections::{HashMap, HashSet};
+
+use log::{trace, warn};
+
+use crate::models::{class_def::ClassDef, statement_value::StatementValue};
+
+/// Stands for the class after the current one in the MRO, so that
+/// `super().save()` in `Child` is recorded as `Child.super().save`.
+pub const SUPER_CALL: &str = "super()";
+
+/// Bases that do not give their subclasses any method signatures to follow.
+const NEUTRAL_BASES: [&str; 7] = [
+    "object",
+    "ABC",
+    "abc.ABC",
+    "Generic",
+    "typing.Generic",
+    "Protocol",
+    "typing.Protocol",
+];
+
+/// Project classes keyed by qualified name, with the edges between them.
+pub struct ClassHierarchy<'a> {
+    classes: HashMap<String, &'a ClassDef>,
+    subclasses: HashMap<String, Vec<String>>,
+    methods: HashSet<String>,
+    variables: HashSet<String>,
+    mros: HashMap<String, Vec<String>>,
+}
+
+/// The C3 linearization of `class_name`, reusing the ones in `mros` and
+/// storing its own there.
+fn linearize(
+    classes: &HashMap<String, &ClassDef>,
+    class_name: &str,
+    mros: &mut HashMap<String, Vec<String>>,
+    visiting: &mut HashSet<String>,
+) -> Vec<String> {
+    let mut mro = vec![class_name.to_string()];
+
+    let Some(class) = classes.get(class_name) else {
+        return mro;
+    };
+
+    if let Some(known_mro) = mros.get(class_name) {
+        return known_mro.clone();
+    }
+
+    // A class inheriting from itself, through a name shadowed somewhere.
+    if !visiting.insert(class_name.to_string()) {
+        return mro;
+    }
+
+    let mut sequences: Vec<Vec<String>> = class
+        .bases
+        .iter()
+        .map(|base| linearize(classes, base, mros, visiting))
+        .collect();
+
+    sequences.push(class.bases.clone());
+    visiting.remove(class_name);
+
+    let mut is_consistent = true;
+
+    loop {
+        sequences.retain(|sequence| !sequence.is_empty());
+
+        let Some(first_sequence) = sequences.first() else {
+            break;
+        };
+
+        let head = sequences.iter().map(|sequence| &sequence[0]).find(|head| {
+            sequences
+                .iter()
+                .all(|sequence| !sequence[1..].contains(head))
+        });
+
+        let head = match head {
+            Some(head) => head.clone(),
+            None => {
+                is_consistent = false;
+                first_sequence[0].clone()
+            }
+        };
+
+        for sequence in &mut sequences {
+            sequence.retain(|name| name != &head);
+        }
+
+        mro.push(head);
+    }
+
+    if !is_consistent {
+        warn!(
+            "no consistent method resolution order for {}, using {}",
+            class_name,
+            mro.join(", ")
+        );
+    }
+
+    mros.insert(class_name.to_string(), mro.clone());
+
+    mro
+}
+
+impl<'a> ClassHierarchy<'a> {
+    pub fn new(statement_value: &'a StatementValue) -> Self {
+        let mut classes = HashMap::new();
+        let mut subclasses: HashMap<String, Vec<String>> = HashMap::new();
+
+        for class in &statement_value.classes {
+            let name = class.qualified_name();
+
+            for base in &class.bases {
+                subclasses
+                    .entry(base.clone())
+                    .or_default()
+                    .push(name.clone());
+            }
+
+            classes.insert(name, class);
+        }
+
+        let methods = statement_value
+            .functions
+            .iter()
+            .filter(|function| function.class_name.is_some())
+            .map(|function| function.qualified_name())
+            .collect();
+
+        let variables = statement_value
+            .variables
+            .iter()
+            .filter(|variable| variable.class_name.is_some())
+            .map(|variable| variable.qualified_name())
+            .collect();
+
+        let mut mros = HashMap::new();
+
+        for name in classes.keys() {
+            linearize(&classes, name, &mut mros, &mut HashSet::new());
+        }
+
+        Self {
+            classes,
+            subclasses,
+            methods,
+            variables,
+            mros,
+        }
+    }
+
+    fn defines(&self, class_name: &str, method_name: &str) -> bool {
+        self.methods
+            .contains(&format!("{}.{}", class_name, method_name))
+    }
+
+    /// Whether the class has a method or a class variable called `name`.
+    fn defines_member(&self, class_name: &str, name: &str) -> bool {
+        let member = format!("{}.{}", class_name, name);
+
+        self.methods.contains(&member) || self.variables.contains(&member)
+    }
+
+    pub fn is_class_variable(&self, name: &str) -> bool {
+        self.variables.contains(name)
+    }
+
+    /// Every class `class_name` inherits from, nearest first. Bases outside
+    /// the project are included but not followed.
+    pub fn ancestors(&self, class_name: &str) -> Vec<String> {
+        let mut ancestors = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![class_name.to_string()];
+
+        while let Some(name) = pending.pop() {
+            let Some(class) = self.classes.get(&name) else {
+                continue;
+            };
+
+            for base in class.bases.iter().rev() {
+                if visited.insert(base.clone()) {
+                    ancestors.push(base.clone());
+                    pending.push(base.clone());
+                }
+            }
+        }
+
+        ancestors
+    }
+
+    /// Every class inheriting from `class_name`, directly or not.
+    pub fn descendants(&self, class_name: &str) -> Vec<String> {
+        let mut descendants = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![class_name.to_string()];
+
+        while let Some(name) = pending.pop() {
+            for subclass in self.subclasses.get(&name).into_iter().flatten() {
+                if visited.insert(subclass.clone()) {
+                    descendants.push(subclass.clone());
+                    pending.push(subclass.clone());
+                }
+            }
+        }
+
+        descendants
+    }
+
+    /// The method resolution order of `class_name`, the class itself first,
+    /// following Python's C3 linearization. Hierarchies C3 rejects fall back
+    /// to taking the next base in order, with a warning logged when building
+    /// the hierarchy.
+    pub fn mro(&self, class_name: &str) -> Vec<String> {
+        match self.mros.get(class_name) {
+            Some(mro) => mro.clone(),
+            None => vec![class_name.to_string()],
+        }
+    }
+
+    /// The class in the MRO that provides `method_name`, a method or a class
+    /// variable.
+    fn resolve_method(&self, mro: &[String], method_name: &str) -> Option<String> {
+        mro.iter()
+            .find(|name| self.defines_member(name, method_name))
+            .map(|name| format!("{}.{}", name, method_name))
+    }
+
+    /// The methods a call to `name` may run, besides `name` itself: the
+    /// definition a subclass inherits, `Child.save` running `Base.save`, and
+    /// the overrides a call through a base class dispatches to.
+    /// `Child.super().save` runs the first definition after `Child`. Class
+    /// variables resolve the same way, `self.timeout` in `Base` reading the
+    /// one a subclass sets.
+    pub fn dispatch_targets(&self, name: &str) -> Vec<String> {
+        let Some((class_name, method_name)) = name.rsplit_once(".") else {
+            return Vec::new();
+        };
+
+        if let Some(class_name) = class_name.strip_suffix(&format!(".{}", SUPER_CALL)) {
+            let mro = self.mro(class_name);
+
+            return self
+                .resolve_method(&mro[1..], method_name)
+                .into_iter()
+                .collect();
+        }
+
+        if !self.classes.contains_key(class_name) {
+            return Vec::new();
+        }
+
+        let mut targets: Vec<String> = self
+            .resolve_method(&self.mro(class_name), method_name)
+            .into_iter()
+            .filter(|target| target != name)
+            .collect();
+
+        targets.extend(
+            self.descendants(class_name)
+                .iter()
+                .filter(|descendant| self.defines_member(descendant, method_name))
+                .map(|descendant| format!("{}.{}", descendant, method_name)),
+        );
+
+        targets
+    }
+
+    /// Whether the method may be replacing one defined by a base class. Bases
+    /// outside the project are assumed to define it.
+    pub fn is_override(&self, class_name: &str, method_name: &str) -> bool {
+        self.ancestors(class_name).iter().any(|ancestor| {
+            match self.classes.contains_key(ancestor) {
+                true => self.defines(ancestor, method_name),
+                false => !NEUTRAL_BASES.contains(&ancestor.as_str()),
+            }
+        })
+    }
+
+    /// Whether a subclass defines its own version of the method.
+    pub fn is_overridden(&self, class_name: &str, method_name: &str) -> bool {
+        self.descendants(class_name)
+            .iter()
+            .any(|descendant| self.defines(descendant, method_name))
+    }
+}
+
+/// Counts the members reached through inheritance as used: the base method a
+/// call through a subclass resolves to, the overrides a call through a base
+/// class may dispatch to, and the class variables read the same ways.
+pub fn fix_inheritance(mut statement_value: StatementValue) -> StatementValue {
+    let hierarchy = ClassHierarchy::new(&statement_value);
+    let expression_value = &statement_value.expression_value;
+
+    let inherited_members: Vec<(String, bool)> = expression_value
+        .used_functions
+        .iter()
+        .chain(&expression_value.used_attributes)
+        .flat_map(|name| hierarchy.dispatch_targets(name))
+        .map(|member| {
+            let is_variable = hierarchy.is_class_variable(&member);
+
+            (member, is_variable)
+        })
+        .collect();
+
+    let expression_value = &mut statement_value.expression_value;
+
+    for (member, is_variable) in inherited_members {
+        let is_new = match is_variable {
+            true => expression_value.used_attributes.insert(member.clone()),
+            false => expression_value.used_functions.insert(member.clone()),
+        };
+
+        if is_new {
+            trace!("{} is used through inheritance", member);
+        }
+    }
+
+    statement_value
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use rustpython_parser::text_size::TextRange;
+
+    use super::*;
+    use crate::models::function_def::FunctionDef;
+
+    /// Classes as `(name, bases)` and methods as `(class, name)`.
+    fn build_statement_value(
+        classes: &[(&str, &[&str])],
+        methods: &[(&str, &str)],
+    ) -> StatementValue {
+        let mut statement_value = StatementValue::new();
+
+        for (name, bases) in classes {
+            let mut class = ClassDef::new(
+                name.to_string(),
+                PathBuf::from("app.py"),
+                TextRange::default(),
+            );
+            class.bases = bases.iter().map(|base| base.to_string()).collect();
+
+            statement_value.classes.insert(class);
+        }
+
+        for (class_name, name) in methods {
+            statement_value.functions.insert(FunctionDef::new(
+                name.to_string(),
+                PathBuf::from("app.py"),
+                Some(class_name.to_string()),
+                TextRange::default(),
+            ));
+        }
+
+        statement_value
+    }
+
+    #[test]
+    fn linearizes_a_diamond() {
+        let statement_value = build_statement_value(
+            &[
+                ("Base", &[]),
+                ("Left", &["Base"]),
+                ("Right", &["Base"]),
+                ("Both", &["Left", "Right"]),
+            ],
+            &[],
+        );
+        let hierarchy = ClassHierarchy::new(&statement_value);
+
+        assert_eq!(hierarchy.mro("Both"), ["Both", "Left", "Right", "Base"]);
+    }
+
+    #[test]
+    fn falls_back_to_base_order_for_an_inconsistent_hierarchy() {
+        let statement_value = build_statement_value(
+            &[
+                ("X", &[]),
+                ("Y", &[]),
+                ("A", &["X", "Y"]),
+                ("B", &["Y", "X"]),
+                ("C", &["A", "B"]),
+            ],
+            &[],
+        );
+        let hierarchy = ClassHierarchy::new(&statement_value);
+
+        assert_eq!(hierarchy.mro("C"), ["C", "A", "B", "X", "Y"]);
+    }
+
+    #[test]
+    fn resolves_super_to_the_next_class_in_the_mro() {
+        let statement_value = build_statement_value(
+            &[
+                ("Base", &[]),
+                ("Left", &["Base"]),
+                ("Right", &["Base"]),
+                ("Both", &["Left", "Right"]),
+            ],
+            &[("Base", "save"), ("Right", "save"), ("Both", "save")],
+        );
+        let hierarchy = ClassHierarchy::new(&statement_value);
+
+        assert_eq!(
+            hierarchy.dispatch_targets("Both.super().save"),
+            ["Right.save"]
+        );
+        assert_eq!(
+            hierarchy.dispatch_targets("Right.super().save"),
+            ["Base.save"]
+        );
+    }
+
+    #[test]
+    fn marks_overrides_used_through_a_base_call() {
+        let mut statement_value = build_statement_value(
+            &[("Base", &[]), ("Child", &["Base"]), ("Other", &[])],
+            &[("Base", "save"), ("Child", "save"), ("Other", "save")],
+        );
+        statement_value
+            .expression_value
+            .used_functions
+            .insert("Base.save".to_string());
+
+        let used_functions = fix_inheritance(statement_value)
+            .expression_value
+            .used_functions;
+
+        assert!(used_functions.contains("Child.save"));
+        assert!(!used_functions.contains("Other.save"));
+    }
+}
@@ -0,0 +1,332 @@
+//! Project settings read from `[tool.py-space]` in `pyproject.toml`, or from a
+//! standalone `py-space.toml` when no pyproject table is found.
+//!
+//! ```toml
+//! [tool.py-space]
+//! roots = ["src"]
+//! exclude = ["src/migrations/**"]
+//! entry-points = ["app.main.run"]
+//! whitelist = ["app.plugins.*"]
+//! min-confidence = 60
+//! enable = ["unused-function", "unused-method"]
+//! format = "json"
+//!
+//! [tool.py-space.unused-arguments]
+//! ignore-self-and-cls = true
+//! ignore-underscore-prefixed = true
+//! ignore-overridden-methods = true
+//! ignore-stub-bodies = true
+//! ```
+//!
+//! Relative paths are resolved against the directory holding the file, which
+//! also becomes the default project root.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use log::debug;
+use serde::Deserialize;
+
+use crate::{
+    errors::py_space::PySpaceError,
+    file_utils::{canonicalize_path, get_current_dir},
+    models::{
+        config::{ArgumentExemptions, Config, OutputFormat},
+        finding_kind::FindingKind,
+    },
+};
+
+const PYPROJECT_FILE: &str = "pyproject.toml";
+const CONFIG_FILE: &str = "py-space.toml";
+const MAX_CONFIDENCE: u8 = 100;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct FileConfig {
+    pub roots: Option<Vec<PathBuf>>,
+    pub include: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+    pub respect_ignore_files: Option<bool>,
+    pub entry_points: Option<Vec<String>>,
+    pub whitelist: Option<Vec<String>>,
+    pub min_confidence: Option<u8>,
+    pub enable: Option<Vec<String>>,
+    pub format: Option<OutputFormat>,
+    pub unused_arguments: Option<UnusedArgumentsConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct UnusedArgumentsConfig {
+    pub ignore_self_and_cls: Option<bool>,
+    pub ignore_underscore_prefixed: Option<bool>,
+    pub ignore_overridden_methods: Option<bool>,
+    pub ignore_stub_bodies: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct PyProject {
+    project: Option<Project>,
+    tool: Option<PyProjectTool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Project {
+    scripts: Option<HashMap<String, toml::Value>>,
+    gui_scripts: Option<HashMap<String, toml::Value>>,
+}
+
+#[derive(Deserialize)]
+struct PyProjectTool {
+    #[serde(rename = "py-space")]
+    py_space: Option<FileConfig>,
+    poetry: Option<Poetry>,
+}
+
+#[derive(Deserialize)]
+struct Poetry {
+    scripts: Option<HashMap<String, toml::Value>>,
+}
+
+fn read_file(path: &Path) -> Result<String, PySpaceError> {
+    match fs::read_to_string(path) {
+        Ok(value) => Ok(value),
+        Err(error) => Err(PySpaceError::FailedToReadFile(format!(
+            "{}: {}",
+            path.display(),
+            error
+        ))),
+    }
+}
+
+fn parse_toml<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, PySpaceError> {
+    match toml::from_str(&read_file(path)?) {
+        Ok(value) => Ok(value),
+        Err(error) => Err(PySpaceError::InvalidConfig(format!(
+            "{}: {}",
+            path.display(),
+            error
+        ))),
+    }
+}
+
+fn read_pyproject(path: &Path) -> Result<Option<FileConfig>, PySpaceError> {
+    let pyproject: PyProject = parse_toml(path)?;
+
+    Ok(pyproject.tool.and_then(|tool| tool.py_space))
+}
+
+fn is_pyproject(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == PYPROJECT_FILE)
+}
+
+fn find_config_file(start_dir: &Path) -> Result<Option<(PathBuf, FileConfig)>, PySpaceError> {
+    for dir in start_dir.ancestors() {
+        let pyproject_path = dir.join(PYPROJECT_FILE);
+
+        if pyproject_path.is_file()
+            && let Some(file_config) = read_pyproject(&pyproject_path)?
+        {
+            return Ok(Some((pyproject_path, file_config)));
+        }
+
+        let config_path = dir.join(CONFIG_FILE);
+
+        if config_path.is_file() {
+            return Ok(Some((config_path.clone(), parse_toml(&config_path)?)));
+        }
+    }
+
+    Ok(None)
+}
+
+fn load_config_file(path: &Path) -> Result<FileConfig, PySpaceError> {
+    if !is_pyproject(path) {
+        return parse_toml(path);
+    }
+
+    match read_pyproject(path)? {
+        Some(value) => Ok(value),
+        None => Err(PySpaceError::InvalidConfig(format!(
+            "{}: missing [tool.py-space] table",
+            path.display()
+        ))),
+    }
+}
+
+fn parse_kinds(names: &[String], path: &Path) -> Result<Vec<FindingKind>, PySpaceError> {
+    let mut kinds = Vec::new();
+
+    for name in names {
+        match FindingKind::from_name(name) {
+            Some(kind) => kinds.push(kind),
+            None => {
+                let known_kinds: Vec<&str> =
+                    FindingKind::ALL.iter().map(|kind| kind.name()).collect();
+
+                return Err(PySpaceError::InvalidConfig(format!(
+                    "{}: unknown finding kind `{}`, expected one of: {}",
+                    path.display(),
+                    name,
+                    known_kinds.join(", ")
+                )));
+            }
+        }
+    }
+
+    Ok(kinds)
+}
+
+impl FileConfig {
+    pub fn apply(self, config: &mut Config, path: &Path) -> Result<(), PySpaceError> {
+        let base_dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        config.project_root = Some(base_dir.to_path_buf());
+
+        if let Some(roots) = self.roots {
+            config.paths = roots.iter().map(|root| base_dir.join(root)).collect();
+        }
+
+        if let Some(include) = self.include {
+            config.include = include;
+        }
+
+        if let Some(exclude) = self.exclude {
+            config.exclude = exclude;
+        }
+
+        if let Some(respect_ignore_files) = self.respect_ignore_files {
+            config.respect_ignore_files = respect_ignore_files;
+        }
+
+        if let Some(entry_points) = self.entry_points {
+            config.entry_points = entry_points;
+        }
+
+        if let Some(whitelist) = self.whitelist {
+            config.whitelist = whitelist;
+        }
+
+        if let Some(min_confidence) = self.min_confidence {
+            if min_confidence > MAX_CONFIDENCE {
+                return Err(PySpaceError::InvalidConfig(format!(
+                    "{}: min-confidence must be between 0 and {}",
+                    path.display(),
+                    MAX_CONFIDENCE
+                )));
+            }
+
+            config.min_confidence = min_confidence;
+        }
+
+        if let Some(enable) = self.enable {
+            config.enabled_kinds = parse_kinds(&enable, path)?;
+        }
+
+        if let Some(format) = self.format {
+            config.output_format = format;
+        }
+
+        if let Some(unused_arguments) = self.unused_arguments {
+            unused_arguments.apply(&mut config.argument_exemptions);
+        }
+
+        Ok(())
+    }
+}
+
+impl UnusedArgumentsConfig {
+    pub fn apply(self, exemptions: &mut ArgumentExemptions) {
+        if let Some(value) = self.ignore_self_and_cls {
+            exemptions.self_and_cls = value;
+        }
+
+        if let Some(value) = self.ignore_underscore_prefixed {
+            exemptions.underscore_prefixed = value;
+        }
+
+        if let Some(value) = self.ignore_overridden_methods {
+            exemptions.overridden_methods = value;
+        }
+
+        if let Some(value) = self.ignore_stub_bodies {
+            exemptions.stub_bodies = value;
+        }
+    }
+}
+
+/// `pkg.cli.main` for a script declared as `pkg.cli:main [extra]`. Scripts
+/// naming a whole module have no function to start from.
+fn parse_script_target(value: &toml::Value) -> Option<String> {
+    let target = value.as_str()?.split('[').next()?.trim();
+    let (module, function) = target.split_once(':')?;
+
+    Some(format!("{}.{}", module.trim(), function.trim()))
+}
+
+/// Functions the console and GUI scripts declared in the project's
+/// `pyproject.toml` start from, under `[project]` or `[tool.poetry]`.
+pub fn read_console_scripts(project_root: &Path) -> Result<Vec<String>, PySpaceError> {
+    let pyproject_path = project_root.join(PYPROJECT_FILE);
+
+    if !pyproject_path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let pyproject: PyProject = parse_toml(&pyproject_path)?;
+    let mut scripts = Vec::new();
+
+    if let Some(project) = pyproject.project {
+        scripts.extend(project.scripts.into_iter().flatten());
+        scripts.extend(project.gui_scripts.into_iter().flatten());
+    }
+
+    if let Some(poetry) = pyproject.tool.and_then(|tool| tool.poetry) {
+        scripts.extend(poetry.scripts.into_iter().flatten());
+    }
+
+    let mut targets: Vec<String> = scripts
+        .iter()
+        .filter_map(|(_, value)| parse_script_target(value))
+        .collect();
+
+    targets.sort();
+    targets.dedup();
+
+    debug!("found {} console scripts", targets.len());
+
+    Ok(targets)
+}
+
+/// Builds a `Config` from `config_path` when given, otherwise from the first
+/// config file found in `start_dir` or one of its parents. Falls back to the
+/// defaults when there is none.
+pub fn load_config(
+    config_path: Option<&Path>,
+    start_dir: Option<&Path>,
+) -> Result<Config, PySpaceError> {
+    let mut config = Config::new();
+
+    let found = match config_path {
+        Some(path) => Some((path.to_path_buf(), load_config_file(path)?)),
+        None => match start_dir {
+            Some(dir) => find_config_file(&canonicalize_path(dir)?)?,
+            None => find_config_file(&get_current_dir()?)?,
+        },
+    };
+
+    if let Some((path, file_config)) = found {
+        debug!("loading configuration from {}", path.display());
+
+        file_config.apply(&mut config, &path)?;
+    }
+
+    Ok(config)
+}
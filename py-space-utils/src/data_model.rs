@@ -0,0 +1,368 @@
+use std::collections::{HashMap, HashSet};
+
+use rustpython_parser::ast;
+
+use crate::{
+    class_hierarchy::SUPER_CALL,
+    models::{
+        statement_value::StatementValue,
+        trigger::{Receiver, Trigger},
+    },
+};
+
+const ITERATION_METHODS: &[&str] = &["__iter__", "__next__", "__getitem__"];
+const HASHING_METHODS: &[&str] = &["__iter__", "__next__", "__getitem__", "__hash__", "__eq__"];
+const ORDERING_METHODS: &[&str] = &["__iter__", "__next__", "__getitem__", "__lt__", "__gt__"];
+
+/// Builtins and standard library functions that call a dunder method of
+/// their argument.
+const BUILTIN_CALLS: [(&str, &[&str]); 38] = [
+    ("str", &["__str__", "__repr__"]),
+    ("print", &["__str__", "__repr__"]),
+    ("repr", &["__repr__"]),
+    ("format", &["__format__", "__str__"]),
+    ("bytes", &["__bytes__"]),
+    ("len", &["__len__"]),
+    ("hash", &["__hash__"]),
+    ("bool", &["__bool__", "__len__"]),
+    ("iter", &["__iter__", "__getitem__"]),
+    ("next", &["__next__"]),
+    ("aiter", &["__aiter__"]),
+    ("anext", &["__anext__"]),
+    ("reversed", &["__reversed__", "__len__", "__getitem__"]),
+    ("abs", &["__abs__"]),
+    ("int", &["__int__", "__index__", "__trunc__"]),
+    ("float", &["__float__", "__index__"]),
+    ("complex", &["__complex__", "__float__", "__index__"]),
+    ("bin", &["__index__"]),
+    ("hex", &["__index__"]),
+    ("oct", &["__index__"]),
+    ("round", &["__round__"]),
+    ("divmod", &["__divmod__", "__rdivmod__"]),
+    ("pow", &["__pow__", "__rpow__"]),
+    ("dir", &["__dir__"]),
+    ("isinstance", &["__instancecheck__"]),
+    ("issubclass", &["__subclasscheck__"]),
+    ("list", ITERATION_METHODS),
+    ("tuple", ITERATION_METHODS),
+    ("enumerate", ITERATION_METHODS),
+    ("zip", ITERATION_METHODS),
+    ("set", HASHING_METHODS),
+    ("frozenset", HASHING_METHODS),
+    ("dict", HASHING_METHODS),
+    ("sorted", ORDERING_METHODS),
+    ("min", ORDERING_METHODS),
+    ("max", ORDERING_METHODS),
+    (
+        "copy.copy",
+        &["__copy__", "__reduce_ex__", "__getstate__", "__setstate__"],
+    ),
+    (
+        "copy.deepcopy",
+        &[
+            "__deepcopy__",
+            "__reduce_ex__",
+            "__getstate__",
+            "__setstate__",
+        ],
+    ),
+];
+
+/// Functions, types and exceptions of the `builtins` module.
+const BUILTIN_NAMES: [&str; 123] = [
+    "abs",
+    "aiter",
+    "all",
+    "anext",
+    "any",
+    "ascii",
+    "bin",
+    "bool",
+    "breakpoint",
+    "bytearray",
+    "bytes",
+    "callable",
+    "chr",
+    "classmethod",
+    "compile",
+    "complex",
+    "delattr",
+    "dict",
+    "dir",
+    "divmod",
+    "enumerate",
+    "eval",
+    "exec",
+    "filter",
+    "float",
+    "format",
+    "frozenset",
+    "getattr",
+    "globals",
+    "hasattr",
+    "hash",
+    "help",
+    "hex",
+    "id",
+    "input",
+    "int",
+    "isinstance",
+    "issubclass",
+    "iter",
+    "len",
+    "list",
+    "locals",
+    "map",
+    "max",
+    "memoryview",
+    "min",
+    "next",
+    "object",
+    "oct",
+    "open",
+    "ord",
+    "pow",
+    "print",
+    "property",
+    "range",
+    "repr",
+    "reversed",
+    "round",
+    "set",
+    "setattr",
+    "slice",
+    "sorted",
+    "staticmethod",
+    "str",
+    "sum",
+    "super",
+    "tuple",
+    "type",
+    "vars",
+    "zip",
+    "ArithmeticError",
+    "AssertionError",
+    "AttributeError",
+    "BaseException",
+    "BaseExceptionGroup",
+    "BlockingIOError",
+    "BrokenPipeError",
+    "BufferError",
+    "ChildProcessError",
+    "ConnectionAbortedError",
+    "ConnectionError",
+    "ConnectionRefusedError",
+    "ConnectionResetError",
+    "EOFError",
+    "Exception",
+    "ExceptionGroup",
+    "FileExistsError",
+    "FileNotFoundError",
+    "FloatingPointError",
+    "GeneratorExit",
+    "ImportError",
+    "IndexError",
+    "InterruptedError",
+    "IsADirectoryError",
+    "KeyError",
+    "KeyboardInterrupt",
+    "LookupError",
+    "MemoryError",
+    "ModuleNotFoundError",
+    "NameError",
+    "NotADirectoryError",
+    "NotImplementedError",
+    "OSError",
+    "OverflowError",
+    "PermissionError",
+    "ProcessLookupError",
+    "RecursionError",
+    "ReferenceError",
+    "RuntimeError",
+    "StopAsyncIteration",
+    "StopIteration",
+    "SyntaxError",
+    "SystemError",
+    "SystemExit",
+    "TimeoutError",
+    "TypeError",
+    "UnboundLocalError",
+    "UnicodeDecodeError",
+    "UnicodeEncodeError",
+    "UnicodeError",
+    "ValueError",
+    "Warning",
+    "ZeroDivisionError",
+];
+
+/// Whether `name` is one of Python's `__special__` names.
+pub fn is_dunder(name: &str) -> bool {
+    name.len() > 4 && name.starts_with("__") && name.ends_with("__")
+}
+
+pub fn is_builtin(name: &str) -> bool {
+    BUILTIN_NAMES.contains(&name)
+}
+
+/// Which classes the dunder methods a trigger calls may belong to.
+enum TriggerTarget {
+    /// A project class, through its instances or the class itself.
+    Class(String),
+    /// Any class, for objects the names do not tell anything about.
+    Any,
+    /// None of the project's classes: functions, modules, builtins and
+    /// whatever comes from outside the project.
+    Nothing,
+}
+
+/// The project names receivers are looked up in.
+struct ProjectNames<'a> {
+    classes: HashSet<String>,
+    functions: HashSet<String>,
+    modules: &'a HashSet<String>,
+}
+
+impl<'a> ProjectNames<'a> {
+    fn new(statement_value: &'a StatementValue) -> Self {
+        Self {
+            classes: statement_value
+                .classes
+                .iter()
+                .map(|class| class.qualified_name())
+                .collect(),
+            functions: statement_value
+                .functions
+                .iter()
+                .map(|function| function.qualified_name())
+                .collect(),
+            modules: &statement_value.call_graph.modules,
+        }
+    }
+
+    /// Whether `name` is defined in one of the project modules, even if not
+    /// as a function or class: a variable, or an attribute set at runtime.
+    fn is_in_project(&self, name: &str) -> bool {
+        let mut prefix = name;
+
+        while let Some((module, _)) = prefix.rsplit_once(".") {
+            if self.modules.contains(module) {
+                return true;
+            }
+
+            prefix = module;
+        }
+
+        false
+    }
+
+    fn find_target(&self, trigger: Trigger, receiver: &Receiver) -> TriggerTarget {
+        let (name, is_instance) = match receiver {
+            Receiver::Name(name) => (name, false),
+            Receiver::Instance(name) => (name, true),
+            Receiver::Literal => return TriggerTarget::Nothing,
+            Receiver::Unknown => return TriggerTarget::Any,
+        };
+
+        if self.classes.contains(name) {
+            // Calling a class makes an instance, and its attributes are found
+            // without `__getattr__`.
+            let is_class_lookup = matches!(
+                trigger,
+                Trigger::Call | Trigger::Attribute(ast::ExprContext::Load)
+            );
+
+            return match !is_instance && is_class_lookup {
+                true => TriggerTarget::Nothing,
+                false => TriggerTarget::Class(name.clone()),
+            };
+        }
+
+        // What a function returns is not known, `make()()`.
+        if self.functions.contains(name) {
+            return match is_instance {
+                true => TriggerTarget::Any,
+                false => TriggerTarget::Nothing,
+            };
+        }
+
+        // Modules, and the methods `super()` finds.
+        if self.modules.contains(name) || name.contains(SUPER_CALL) {
+            return TriggerTarget::Nothing;
+        }
+
+        match self.is_in_project(name) {
+            true => TriggerTarget::Any,
+            false => TriggerTarget::Nothing,
+        }
+    }
+}
+
+/// Which dunder methods the project gives Python a reason to call. Methods
+/// no syntax is tied to, `__init__`, `__new__`, `__del__` or
+/// `__init_subclass__`, are called whenever the class is used at all.
+pub struct DataModel {
+    protocol_methods: HashSet<&'static str>,
+    /// Methods called on any class, by builtins or by syntax working on
+    /// objects the names do not tell anything about.
+    triggered_methods: HashSet<&'static str>,
+    /// Methods called on a known class, keyed by its qualified name.
+    class_methods: HashMap<String, HashSet<&'static str>>,
+}
+
+impl DataModel {
+    pub fn new(statement_value: &StatementValue) -> Self {
+        let expression_value = &statement_value.expression_value;
+        let builtin_methods = BUILTIN_CALLS.iter().flat_map(|(_, methods)| *methods);
+
+        let protocol_methods = Trigger::all()
+            .into_iter()
+            .flat_map(|trigger| trigger.dunder_methods())
+            .chain(builtin_methods)
+            .copied()
+            .collect();
+
+        let builtin_methods = BUILTIN_CALLS
+            .iter()
+            .filter(|(name, _)| expression_value.used_functions.contains(*name))
+            .flat_map(|(_, methods)| *methods);
+
+        let mut triggered_methods: HashSet<&'static str> = builtin_methods.copied().collect();
+        let mut class_methods: HashMap<String, HashSet<&'static str>> = HashMap::new();
+        let project_names = ProjectNames::new(statement_value);
+
+        for (trigger, receiver) in &expression_value.triggers {
+            let methods = trigger.dunder_methods();
+
+            match project_names.find_target(*trigger, receiver) {
+                TriggerTarget::Class(class_name) => {
+                    class_methods.entry(class_name).or_default().extend(methods)
+                }
+                TriggerTarget::Any => triggered_methods.extend(methods),
+                TriggerTarget::Nothing => {}
+            }
+        }
+
+        Self {
+            protocol_methods,
+            triggered_methods,
+            class_methods,
+        }
+    }
+
+    /// Whether Python may call the dunder method on instances of a class
+    /// with the given MRO. Syntax working on a base class may be working on
+    /// an instance of this one.
+    pub fn is_called(&self, mro: &[String], method_name: &str) -> bool {
+        if !self.protocol_methods.contains(method_name)
+            || self.triggered_methods.contains(method_name)
+        {
+            return true;
+        }
+
+        mro.iter().any(|class_name| {
+            self.class_methods
+                .get(class_name)
+                .is_some_and(|methods| methods.contains(method_name))
+        })
+    }
+}
@@ -5,6 +5,11 @@ pub enum PySpaceError {
     CantGetCurrentPath(String),
     FailedToReadFile(String),
     FailedToParseFile(String),
+    InvalidGlob(String),
+    InvalidRoot(String),
+    InvalidConfig(String),
+    AllFilesFailedToParse(usize),
+    FailedToBuildThreadPool(String),
 }
 
 impl std::fmt::Display for PySpaceError {
@@ -21,6 +26,26 @@ impl std::fmt::Display for PySpaceError {
             PySpaceError::FailedToParseFile(message) => {
                 write!(f, "Faild to parse file: {}", message)
             }
+
+            PySpaceError::InvalidGlob(message) => {
+                write!(f, "Invalid glob pattern: {}", message)
+            }
+
+            PySpaceError::InvalidRoot(message) => {
+                write!(f, "Invalid root path: {}", message)
+            }
+
+            PySpaceError::InvalidConfig(message) => {
+                write!(f, "Invalid configuration: {}", message)
+            }
+
+            PySpaceError::AllFilesFailedToParse(count) => {
+                write!(f, "All {} files failed to parse", count)
+            }
+
+            PySpaceError::FailedToBuildThreadPool(message) => {
+                write!(f, "Failed to build thread pool: {}", message)
+            }
         }
     }
 }
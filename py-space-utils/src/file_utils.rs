@@ -1,11 +1,298 @@
-use crate::errors::py_space::PySpaceError;
-use walkdir::WalkDir;
+use std::{
+    collections::BTreeSet,
+    path::{Component, Path, PathBuf},
+};
 
-pub fn get_files_iterator() -> Result<WalkDir, PySpaceError> {
-    let path = match std::env::current_dir() {
-        Ok(value) => value,
-        Err(error) => return Err(PySpaceError::CantGetCurrentPath(error.to_string())),
+use crate::{errors::py_space::PySpaceError, models::config::Config};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::{DirEntry, Walk, WalkBuilder};
+use log::{debug, trace, warn};
+
+const INIT_FILE: &str = "__init__.py";
+const INIT_MODULE: &str = "__init__";
+const VIRTUALENV_MARKER: &str = "pyvenv.cfg";
+
+const EXCLUDED_DIRS: [&str; 13] = [
+    ".git",
+    ".hg",
+    ".svn",
+    ".tox",
+    ".nox",
+    ".eggs",
+    ".mypy_cache",
+    ".pytest_cache",
+    ".ruff_cache",
+    "__pycache__",
+    "__pypackages__",
+    "site-packages",
+    "node_modules",
+];
+
+/// Build output directories, skipped only at the project root since a
+/// package can have a `build` module of its own.
+const ROOT_EXCLUDED_DIRS: [&str; 2] = ["build", "dist"];
+
+pub(crate) fn get_current_dir() -> Result<PathBuf, PySpaceError> {
+    match std::env::current_dir() {
+        Ok(value) => Ok(value),
+        Err(error) => Err(PySpaceError::CantGetCurrentPath(error.to_string())),
+    }
+}
+
+pub(crate) fn canonicalize_path(path: &Path) -> Result<PathBuf, PySpaceError> {
+    match path.canonicalize() {
+        Ok(value) => Ok(value),
+        Err(error) => Err(PySpaceError::InvalidRoot(format!(
+            "{}: {}",
+            path.display(),
+            error
+        ))),
+    }
+}
+
+pub fn get_project_root(config: &Config) -> Result<PathBuf, PySpaceError> {
+    match &config.project_root {
+        Some(value) => canonicalize_path(value),
+        None => canonicalize_path(&get_current_dir()?),
+    }
+}
+
+pub fn get_root_paths(config: &Config) -> Result<Vec<PathBuf>, PySpaceError> {
+    if config.paths.is_empty() {
+        return Ok(vec![canonicalize_path(&get_current_dir()?)?]);
+    }
+
+    let mut roots = BTreeSet::new();
+
+    for path in &config.paths {
+        roots.insert(canonicalize_path(path)?);
+    }
+
+    let mut unique_roots: Vec<PathBuf> = Vec::new();
+
+    for root in roots {
+        let is_covered = unique_roots
+            .iter()
+            .any(|unique_root| root.starts_with(unique_root));
+
+        if is_covered {
+            debug!("skipping root {} covered by another root", root.display());
+        } else {
+            unique_roots.push(root);
+        }
+    }
+
+    Ok(unique_roots)
+}
+
+fn is_virtualenv(path: &Path) -> bool {
+    path.join(VIRTUALENV_MARKER).is_file()
+}
+
+fn is_excluded_dir(entry: &DirEntry, project_root: &Path, exclude: &Option<GlobSet>) -> bool {
+    let path = entry.path();
+    let is_dir = entry
+        .file_type()
+        .is_some_and(|file_type| file_type.is_dir());
+
+    if entry.depth() == 0 || !is_dir {
+        return false;
+    }
+
+    let is_at_root = path.parent() == Some(project_root);
+
+    let is_default_excluded = entry.file_name().to_str().is_some_and(|name| {
+        EXCLUDED_DIRS.contains(&name) || (is_at_root && ROOT_EXCLUDED_DIRS.contains(&name))
+    });
+
+    let is_user_excluded = exclude
+        .as_ref()
+        .is_some_and(|glob_set| glob_set.is_match(get_relative_path(path, project_root)));
+
+    if is_default_excluded || is_user_excluded {
+        trace!("skipping excluded directory {}", path.display());
+
+        return true;
+    }
+
+    if is_virtualenv(path) {
+        trace!("skipping virtualenv {}", path.display());
+
+        return true;
+    }
+
+    false
+}
+
+pub fn get_files_iterator(
+    path: &Path,
+    project_root: &Path,
+    config: &Config,
+    exclude: &Option<GlobSet>,
+) -> Walk {
+    let project_root = project_root.to_path_buf();
+    let exclude = exclude.clone();
+
+    WalkBuilder::new(path)
+        .hidden(false)
+        .require_git(false)
+        .git_ignore(config.respect_ignore_files)
+        .git_global(config.respect_ignore_files)
+        .git_exclude(config.respect_ignore_files)
+        .ignore(config.respect_ignore_files)
+        .parents(config.respect_ignore_files)
+        .filter_entry(move |entry| !is_excluded_dir(entry, &project_root, &exclude))
+        .build()
+}
+
+pub fn build_glob_set(patterns: &[String]) -> Result<Option<GlobSet>, PySpaceError> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        let glob = match Glob::new(pattern) {
+            Ok(value) => value,
+            Err(error) => return Err(PySpaceError::InvalidGlob(error.to_string())),
+        };
+
+        builder.add(glob);
+    }
+
+    match builder.build() {
+        Ok(value) => Ok(Some(value)),
+        Err(error) => Err(PySpaceError::InvalidGlob(error.to_string())),
+    }
+}
+
+pub fn is_path_selected(
+    path: &Path,
+    project_root: &Path,
+    include: &Option<GlobSet>,
+    exclude: &Option<GlobSet>,
+) -> bool {
+    let relative_path = get_relative_path(path, project_root);
+
+    let is_included = include
+        .as_ref()
+        .is_none_or(|glob_set| glob_set.is_match(relative_path));
+
+    let is_excluded = exclude
+        .as_ref()
+        .is_some_and(|glob_set| glob_set.is_match(relative_path));
+
+    is_included && !is_excluded
+}
+
+pub fn get_relative_path<'a>(path: &'a Path, project_root: &Path) -> &'a Path {
+    path.strip_prefix(project_root).unwrap_or(path)
+}
+
+fn is_regular_package(path: &Path) -> bool {
+    path.join(INIT_FILE).is_file()
+}
+
+/// The directory module names are counted from: the analyzed root `path` was
+/// found under, or the directory of a file given as a root.
+fn get_source_root(path: &Path, roots: &[PathBuf]) -> PathBuf {
+    let root = roots
+        .iter()
+        .filter(|root| path.starts_with(root))
+        .max_by_key(|root| root.components().count());
+
+    match root {
+        Some(value) if value.is_dir() => value.clone(),
+        _ => path.parent().unwrap_or(path).to_path_buf(),
+    }
+}
+
+/// The directory imports of `path` start from: the source root for modules
+/// outside a regular package, climbing out of the regular packages around
+/// the module or the root, even above the analyzed root.
+fn get_import_root(path: &Path, source_root: &Path) -> PathBuf {
+    let mut import_root = match path.parent() {
+        Some(value) if is_regular_package(value) => value,
+        _ => source_root,
     };
 
-    Ok(WalkDir::new(path))
+    while is_regular_package(import_root) {
+        import_root = match import_root.parent() {
+            Some(value) => value,
+            None => break,
+        };
+    }
+
+    import_root.to_path_buf()
+}
+
+/// The dotted module name of `path`, counted from the root it was found
+/// under. Only plain path parts make it into the name, never a drive or `/`.
+pub fn get_module_name(path: &Path, roots: &[PathBuf]) -> String {
+    let import_root = get_import_root(path, &get_source_root(path, roots));
+    let relative_path = path.strip_prefix(&import_root).unwrap_or(path);
+    let relative_path = relative_path.with_extension("");
+
+    let mut parts: Vec<String> = relative_path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect();
+
+    if parts.last().is_some_and(|part| part == INIT_MODULE) {
+        parts.pop();
+    }
+
+    parts.join(".")
+}
+
+pub fn is_package_file(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == INIT_FILE)
+}
+
+pub fn collect_python_files(
+    config: &Config,
+    project_root: &Path,
+) -> Result<Vec<PathBuf>, PySpaceError> {
+    let include = build_glob_set(&config.include)?;
+    let exclude = build_glob_set(&config.exclude)?;
+
+    let mut files = BTreeSet::new();
+
+    for root in get_root_paths(config)? {
+        if root.is_file() {
+            files.insert(root);
+
+            continue;
+        }
+
+        for file_result in get_files_iterator(&root, project_root, config, &exclude) {
+            let file = match file_result {
+                Ok(value) => value,
+                Err(error) => {
+                    warn!("failed to walk {}: {}", root.display(), error);
+
+                    continue;
+                }
+            };
+
+            let path = file.path();
+            let is_python_file = path.extension().is_some_and(|extension| extension == "py");
+
+            if !is_python_file {
+                continue;
+            }
+
+            if !is_path_selected(path, project_root, &include, &exclude) {
+                continue;
+            }
+
+            files.insert(path.to_path_buf());
+        }
+    }
+
+    Ok(files.into_iter().collect())
 }
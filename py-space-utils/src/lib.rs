@@ -1,8 +1,14 @@
 pub mod analyzer;
 pub mod assignments;
+pub mod class_hierarchy;
+pub mod config_file;
+pub mod data_model;
 pub mod errors;
 pub mod file_utils;
 pub mod models;
 pub mod parse_expression;
 pub mod parse_statement;
 pub mod possible_functions;
+pub mod reachability;
+pub mod resolve_names;
+pub mod unused_imports;
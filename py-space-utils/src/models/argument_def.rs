@@ -0,0 +1,27 @@
+use rustpython_parser::text_size::TextRange;
+
+use super::{line_index::LineIndex, location::Location};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ArgumentDef {
+    pub name: String,
+    pub range: TextRange,
+    pub location: Location,
+    pub end_location: Location,
+}
+
+impl ArgumentDef {
+    pub fn new(name: String, range: TextRange) -> Self {
+        Self {
+            name,
+            range,
+            location: Location::default(),
+            end_location: Location::default(),
+        }
+    }
+
+    pub fn resolve_location(&mut self, line_index: &LineIndex) {
+        self.location = line_index.location(self.range.start());
+        self.end_location = line_index.location(self.range.end());
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+
+/// The key module-level code is recorded under until the module is known.
+const MODULE_CODE: &str = "";
+
+fn merge_edges(
+    edges: &mut HashMap<String, HashSet<String>>,
+    caller: String,
+    names: impl IntoIterator<Item = String>,
+) {
+    edges.entry(caller).or_default().extend(names);
+}
+
+/// Names each function uses, keyed by the function. Code outside any function
+/// body, which runs when its module is imported, is recorded under the module.
+/// `calls` only holds the names that are called, `edges` every name used.
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    pub edges: HashMap<String, HashSet<String>>,
+    pub calls: HashMap<String, HashSet<String>>,
+    pub modules: HashSet<String>,
+}
+
+impl CallGraph {
+    pub fn new() -> Self {
+        Self {
+            edges: HashMap::new(),
+            calls: HashMap::new(),
+            modules: HashSet::new(),
+        }
+    }
+
+    pub fn insert_module_edges(&mut self, names: impl IntoIterator<Item = String>) {
+        merge_edges(&mut self.edges, MODULE_CODE.to_string(), names);
+    }
+
+    pub fn insert_module_calls(&mut self, names: impl IntoIterator<Item = String>) {
+        merge_edges(&mut self.calls, MODULE_CODE.to_string(), names);
+    }
+
+    /// Moves the names used outside any function body to `caller`, for the
+    /// body of the function being built.
+    pub fn attribute_module_edges(&mut self, caller: &str) {
+        if let Some(names) = self.edges.remove(MODULE_CODE) {
+            merge_edges(&mut self.edges, caller.to_string(), names);
+        }
+
+        if let Some(names) = self.calls.remove(MODULE_CODE) {
+            merge_edges(&mut self.calls, caller.to_string(), names);
+        }
+    }
+
+    pub fn merge_call_graph(&mut self, call_graph: CallGraph) {
+        for (caller, names) in call_graph.edges {
+            merge_edges(&mut self.edges, caller, names);
+        }
+
+        for (caller, names) in call_graph.calls {
+            merge_edges(&mut self.calls, caller, names);
+        }
+
+        self.modules.extend(call_graph.modules);
+    }
+
+    pub fn set_module(&mut self, module: &str) {
+        let qualify = |caller: String| match (caller.as_str(), module.is_empty()) {
+            (MODULE_CODE, _) => module.to_string(),
+            (_, true) => caller,
+            (_, false) => format!("{}.{}", module, caller),
+        };
+
+        for edges in [&mut self.edges, &mut self.calls] {
+            for (caller, names) in std::mem::take(edges) {
+                merge_edges(edges, qualify(caller), names);
+            }
+        }
+
+        self.modules.insert(module.to_string());
+    }
+
+    pub fn callees(&self, caller: &str) -> impl Iterator<Item = &String> {
+        self.edges.get(caller).into_iter().flatten()
+    }
+}
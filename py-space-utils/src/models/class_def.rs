@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use rustpython_parser::text_size::TextRange;
+
+use super::{line_index::LineIndex, location::Location};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ClassDef {
+    pub name: String,
+    pub file: PathBuf,
+    pub module: String,
+    pub bases: Vec<String>,
+    pub range: TextRange,
+    pub location: Location,
+    pub end_location: Location,
+}
+
+impl ClassDef {
+    pub fn new(name: String, file: PathBuf, range: TextRange) -> Self {
+        Self {
+            name,
+            file,
+            module: String::new(),
+            bases: Vec::new(),
+            range,
+            location: Location::default(),
+            end_location: Location::default(),
+        }
+    }
+
+    pub fn qualified_name(&self) -> String {
+        if self.module.is_empty() {
+            return self.name.clone();
+        }
+
+        format!("{}.{}", self.module, self.name)
+    }
+
+    pub fn resolve_location(&mut self, line_index: &LineIndex) {
+        self.location = line_index.location(self.range.start());
+        self.end_location = line_index.location(self.range.end());
+    }
+}
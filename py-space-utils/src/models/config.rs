@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use super::finding_kind::FindingKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+/// Parameters the unused argument check leaves alone.
+#[derive(Debug, Clone, Copy)]
+pub struct ArgumentExemptions {
+    pub self_and_cls: bool,
+    pub underscore_prefixed: bool,
+    pub overridden_methods: bool,
+    pub stub_bodies: bool,
+}
+
+impl ArgumentExemptions {
+    pub fn new() -> Self {
+        Self {
+            self_and_cls: true,
+            underscore_prefixed: true,
+            overridden_methods: true,
+            stub_bodies: true,
+        }
+    }
+}
+
+impl Default for ArgumentExemptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub paths: Vec<PathBuf>,
+    pub project_root: Option<PathBuf>,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub respect_ignore_files: bool,
+    pub entry_points: Vec<String>,
+    pub whitelist: Vec<String>,
+    pub min_confidence: u8,
+    pub enabled_kinds: Vec<FindingKind>,
+    pub argument_exemptions: ArgumentExemptions,
+    pub output_format: OutputFormat,
+    pub jobs: Option<usize>,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self {
+            paths: Vec::new(),
+            project_root: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_ignore_files: true,
+            entry_points: Vec::new(),
+            whitelist: Vec::new(),
+            min_confidence: 0,
+            enabled_kinds: FindingKind::ALL.to_vec(),
+            argument_exemptions: ArgumentExemptions::new(),
+            output_format: OutputFormat::Text,
+            jobs: None,
+        }
+    }
+
+    pub fn is_kind_enabled(&self, kind: FindingKind) -> bool {
+        self.enabled_kinds.contains(&kind)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,13 +1,22 @@
 use std::collections::HashSet;
 
-use super::function_def::FunctionDef;
+use super::{
+    function_def::FunctionDef,
+    trigger::{Receiver, Trigger},
+};
 
 #[derive(Debug, Clone)]
 pub struct ExpressionValue {
     pub used_functions: HashSet<String>,
     pub used_names: HashSet<String>,
+    pub assigned_names: HashSet<String>,
+    /// Plain names read here that no lambda or comprehension inside binds.
+    pub free_names: HashSet<String>,
     pub used_attributes: HashSet<String>,
     pub possible_functions: HashSet<FunctionDef>,
+    /// Syntax used here that makes Python call dunder methods, with the
+    /// object it works on.
+    pub triggers: HashSet<(Trigger, Receiver)>,
 }
 
 impl ExpressionValue {
@@ -15,19 +24,26 @@ impl ExpressionValue {
         Self {
             used_functions: HashSet::new(),
             used_names: HashSet::new(),
+            assigned_names: HashSet::new(),
+            free_names: HashSet::new(),
             used_attributes: HashSet::new(),
             possible_functions: HashSet::new(),
+            triggers: HashSet::new(),
         }
     }
 
     pub fn merge_expression_result(&mut self, expression_value: ExpressionValue) {
         self.used_functions.extend(expression_value.used_functions);
         self.used_names.extend(expression_value.used_names);
+        self.assigned_names.extend(expression_value.assigned_names);
+        self.free_names.extend(expression_value.free_names);
         self.used_attributes
             .extend(expression_value.used_attributes);
 
         self.possible_functions
             .extend(expression_value.possible_functions);
+
+        self.triggers.extend(expression_value.triggers);
     }
 
     pub fn insert(&mut self, value: String) -> bool {
@@ -38,6 +54,14 @@ impl ExpressionValue {
         self.used_names.insert(value)
     }
 
+    pub fn insert_assigned_name(&mut self, value: String) -> bool {
+        self.assigned_names.insert(value)
+    }
+
+    pub fn insert_free_name(&mut self, value: String) -> bool {
+        self.free_names.insert(value)
+    }
+
     pub fn insert_attribute(&mut self, value: String) -> bool {
         self.used_attributes.insert(value)
     }
@@ -45,4 +69,8 @@ impl ExpressionValue {
     pub fn insert_possible_function(&mut self, value: FunctionDef) -> bool {
         self.possible_functions.insert(value)
     }
+
+    pub fn insert_trigger(&mut self, value: Trigger, receiver: Receiver) -> bool {
+        self.triggers.insert((value, receiver))
+    }
 }
@@ -1,10 +1,17 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
-use super::imports::Imports;
+use super::{
+    finding_kind::FindingKind, imports::Imports, line_index::LineIndex, suppression::Suppression,
+};
 
 pub struct FileData {
     pub path: PathBuf,
     pub imports: HashMap<String, Vec<Imports>>,
+    pub exported_names: HashSet<String>,
+    pub suppressions: HashMap<usize, Suppression>,
 }
 
 impl FileData {
@@ -12,6 +19,46 @@ impl FileData {
         Self {
             path,
             imports: HashMap::new(),
+            exported_names: HashSet::new(),
+            suppressions: HashMap::new(),
+        }
+    }
+
+    pub fn insert_import(&mut self, import_data: Imports) {
+        self.imports
+            .entry(import_data.path.clone())
+            .or_default()
+            .push(import_data);
+    }
+
+    pub fn merge_file_data(&mut self, file_data: FileData) {
+        for (path, imports) in file_data.imports {
+            self.imports.entry(path).or_default().extend(imports);
+        }
+
+        self.exported_names.extend(file_data.exported_names);
+        self.suppressions.extend(file_data.suppressions);
+    }
+
+    pub fn is_suppressed(&self, line: usize, kind: FindingKind) -> bool {
+        self.suppressions
+            .get(&line)
+            .is_some_and(|suppression| suppression.covers(kind))
+    }
+
+    pub fn set_module(&mut self, module: &str) {
+        for imports in self.imports.values_mut() {
+            for import_data in imports {
+                import_data.module = module.to_string();
+            }
+        }
+    }
+
+    pub fn resolve_locations(&mut self, line_index: &LineIndex) {
+        for imports in self.imports.values_mut() {
+            for import_data in imports {
+                import_data.resolve_location(line_index);
+            }
         }
     }
 }
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use super::{finding_kind::FindingKind, location::Location};
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub kind: FindingKind,
+    pub qualified_name: String,
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub confidence: u8,
+    pub reason: String,
+}
+
+impl Finding {
+    pub fn new(
+        kind: FindingKind,
+        qualified_name: String,
+        file: PathBuf,
+        location: Location,
+        end_location: Location,
+        confidence: u8,
+        reason: String,
+    ) -> Self {
+        Self {
+            kind,
+            qualified_name,
+            file,
+            line: location.line,
+            column: location.column,
+            end_line: end_location.line,
+            end_column: end_location.column,
+            confidence,
+            reason,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FindingKind {
+    UnusedFunction,
+    UnusedMethod,
+    UnusedClass,
+    UnusedImport,
+    UnusedVariable,
+    UnusedArgument,
+    UnreachableCode,
+    DeadCode,
+}
+
+impl FindingKind {
+    pub const ALL: [FindingKind; 8] = [
+        FindingKind::UnusedFunction,
+        FindingKind::UnusedMethod,
+        FindingKind::UnusedClass,
+        FindingKind::UnusedImport,
+        FindingKind::UnusedVariable,
+        FindingKind::UnusedArgument,
+        FindingKind::UnreachableCode,
+        FindingKind::DeadCode,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FindingKind::UnusedFunction => "unused-function",
+            FindingKind::UnusedMethod => "unused-method",
+            FindingKind::UnusedClass => "unused-class",
+            FindingKind::UnusedImport => "unused-import",
+            FindingKind::UnusedVariable => "unused-variable",
+            FindingKind::UnusedArgument => "unused-argument",
+            FindingKind::UnreachableCode => "unreachable-code",
+            FindingKind::DeadCode => "dead-code",
+        }
+    }
+
+    pub fn rule_id(&self) -> &'static str {
+        match self {
+            FindingKind::UnusedFunction => "PS001",
+            FindingKind::UnusedMethod => "PS002",
+            FindingKind::UnusedClass => "PS003",
+            FindingKind::UnusedImport => "PS004",
+            FindingKind::UnusedVariable => "PS005",
+            FindingKind::UnusedArgument => "PS006",
+            FindingKind::UnreachableCode => "PS007",
+            FindingKind::DeadCode => "PS008",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            FindingKind::UnusedFunction => "Unused function",
+            FindingKind::UnusedMethod => "Unused method",
+            FindingKind::UnusedClass => "Unused class",
+            FindingKind::UnusedImport => "Unused import",
+            FindingKind::UnusedVariable => "Unused variable",
+            FindingKind::UnusedArgument => "Unused argument",
+            FindingKind::UnreachableCode => "Unreachable code",
+            FindingKind::DeadCode => "Dead code",
+        }
+    }
+
+    pub fn help(&self) -> &'static str {
+        match self {
+            FindingKind::UnusedFunction => {
+                "The function is defined but never called or referenced anywhere in the \
+                 analyzed project. Remove it unless it is called dynamically or from \
+                 outside the project."
+            }
+            FindingKind::UnusedMethod => {
+                "The method is defined but never called on its class or an instance of it \
+                 anywhere in the analyzed project. Remove it unless it is invoked \
+                 dynamically or by a framework."
+            }
+            FindingKind::UnusedClass => {
+                "The class is never instantiated, subclassed, used in an annotation or \
+                 referenced anywhere else in the analyzed project. Remove it unless it is \
+                 looked up dynamically or registered by a framework."
+            }
+            FindingKind::UnusedImport => {
+                "The imported name is never referenced in the module that imports it. \
+                 Remove the import, or list the name in `__all__` or import it as \
+                 `import x as x` if it is meant to be re-exported."
+            }
+            FindingKind::UnusedVariable => {
+                "The variable is assigned but never read: a module or class variable \
+                 anywhere in the analyzed project, or a local variable in its own function \
+                 or the functions nested in it. Remove it unless it is read dynamically, by \
+                 a framework or from outside the project."
+            }
+            FindingKind::UnusedArgument => {
+                "The parameter is never read in the body of its function. Remove it, or \
+                 prefix it with an underscore if the signature has to stay as it is."
+            }
+            FindingKind::UnreachableCode => {
+                "The statements follow a `return`, `raise`, `break`, `continue` or \
+                 `sys.exit()`, or sit in a branch whose condition is a constant, so they \
+                 can never execute. Remove them or fix the control flow that skips them."
+            }
+            FindingKind::DeadCode => {
+                "The function, method or class is used, but only by code that never runs: \
+                 nothing reaches it from module-level code, console scripts, tests, \
+                 decorated handlers or the configured entry points. Remove it together \
+                 with the code that uses it."
+            }
+        }
+    }
+
+    /// Codes other linters use for the same problem, honored in `# noqa`
+    /// comments so existing suppressions keep working.
+    pub fn compatible_codes(&self) -> &'static [&'static str] {
+        match self {
+            FindingKind::UnusedImport => &["F401"],
+            FindingKind::UnusedVariable => &["F841"],
+            FindingKind::UnusedArgument => &["ARG001", "ARG002"],
+            _ => &[],
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+impl std::fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
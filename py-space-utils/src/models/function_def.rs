@@ -1,18 +1,42 @@
 use std::path::PathBuf;
 
+use rustpython_parser::text_size::TextRange;
+
+use super::{
+    argument_def::ArgumentDef, line_index::LineIndex, location::Location, variable_def::VariableDef,
+};
+
+const DEFINITION_KEYWORD: &str = "def";
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct FunctionDef {
     pub name: String,
     pub file: PathBuf,
     pub class_name: Option<String>,
+    pub module: String,
+    pub range: TextRange,
+    pub location: Location,
+    pub end_location: Location,
+    pub unused_arguments: Vec<ArgumentDef>,
+    pub unused_locals: Vec<VariableDef>,
+    pub is_stub: bool,
+    pub decorators: Vec<String>,
 }
 
 impl FunctionDef {
-    pub fn new(name: String, file: PathBuf, class_name: Option<String>) -> Self {
+    pub fn new(name: String, file: PathBuf, class_name: Option<String>, range: TextRange) -> Self {
         Self {
             name,
             file,
             class_name,
+            module: String::new(),
+            range,
+            location: Location::default(),
+            end_location: Location::default(),
+            unused_arguments: Vec::new(),
+            unused_locals: Vec::new(),
+            is_stub: false,
+            decorators: Vec::new(),
         }
     }
 
@@ -25,4 +49,29 @@ impl FunctionDef {
 
         class_name + "." + &self.name
     }
+
+    pub fn qualified_name(&self) -> String {
+        if self.module.is_empty() {
+            return self.full_name();
+        }
+
+        format!("{}.{}", self.module, self.full_name())
+    }
+
+    pub fn resolve_location(&mut self, line_index: &LineIndex) {
+        let name_start = line_index
+            .find_definition_name(self.range, DEFINITION_KEYWORD, &self.name)
+            .unwrap_or(self.range.start());
+
+        self.location = line_index.location(name_start);
+        self.end_location = line_index.location(self.range.end());
+
+        for argument in &mut self.unused_arguments {
+            argument.resolve_location(line_index);
+        }
+
+        for variable in &mut self.unused_locals {
+            variable.resolve_location(line_index);
+        }
+    }
 }
@@ -0,0 +1,220 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    path::PathBuf,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GraphNodeKind {
+    Module,
+    Class,
+    Function,
+    Method,
+}
+
+impl GraphNodeKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GraphNodeKind::Module => "module",
+            GraphNodeKind::Class => "class",
+            GraphNodeKind::Function => "function",
+            GraphNodeKind::Method => "method",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphNode {
+    pub kind: GraphNodeKind,
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+/// Who calls whom among the analyzed modules, functions and classes, where
+/// calling a class means instantiating it. Both maps are ordered so the same
+/// project always gives the same output.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    pub nodes: BTreeMap<String, GraphNode>,
+    pub edges: BTreeSet<(String, String)>,
+}
+
+fn matches_prefix(name: &str, prefix: &str) -> bool {
+    name == prefix || name.starts_with(&format!("{}.", prefix))
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self {
+            nodes: BTreeMap::new(),
+            edges: BTreeSet::new(),
+        }
+    }
+
+    pub fn insert_node(&mut self, name: String, node: GraphNode) {
+        self.nodes.insert(name, node);
+    }
+
+    /// Adds the edge when both ends are nodes of the graph.
+    pub fn insert_edge(&mut self, caller: &str, callee: &str) -> bool {
+        if !self.nodes.contains_key(caller) || !self.nodes.contains_key(callee) {
+            return false;
+        }
+
+        self.edges.insert((caller.to_string(), callee.to_string()))
+    }
+
+    /// Keeps what the nodes under `module_prefix` call, following calls at
+    /// most `depth` steps away. Without a prefix the walk starts from
+    /// module-level code, and without a depth it goes as far as calls lead.
+    pub fn filter(&self, module_prefix: Option<&str>, depth: Option<usize>) -> Graph {
+        if module_prefix.is_none() && depth.is_none() {
+            return self.clone();
+        }
+
+        let mut callees: HashMap<&str, Vec<&str>> = HashMap::new();
+
+        for (caller, callee) in &self.edges {
+            callees.entry(caller).or_default().push(callee);
+        }
+
+        let mut distances: HashMap<&str, usize> = HashMap::new();
+        let mut pending = VecDeque::new();
+
+        for (name, node) in &self.nodes {
+            let is_start = match module_prefix {
+                Some(prefix) => matches_prefix(name, prefix),
+                None => node.kind == GraphNodeKind::Module,
+            };
+
+            if is_start {
+                distances.insert(name, 0);
+                pending.push_back(name.as_str());
+            }
+        }
+
+        let mut graph = Graph::new();
+
+        while let Some(name) = pending.pop_front() {
+            let distance = distances[name];
+
+            graph.insert_node(name.to_string(), self.nodes[name].clone());
+
+            if depth.is_some_and(|depth| distance >= depth) {
+                continue;
+            }
+
+            for callee in callees.get(name).into_iter().flatten() {
+                if !distances.contains_key(callee) {
+                    distances.insert(callee, distance + 1);
+                    pending.push_back(callee);
+                }
+            }
+        }
+
+        for (caller, callee) in &self.edges {
+            let is_followed = distances
+                .get(caller.as_str())
+                .is_some_and(|distance| depth.is_none_or(|depth| *distance < depth));
+
+            if is_followed {
+                graph.insert_edge(caller, callee);
+            }
+        }
+
+        graph
+    }
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_graph() -> Graph {
+        let mut graph = Graph::new();
+
+        let nodes = [
+            ("app", GraphNodeKind::Module),
+            ("app.main", GraphNodeKind::Function),
+            ("app.util", GraphNodeKind::Module),
+            ("app.util.load", GraphNodeKind::Function),
+            ("app.util.parse", GraphNodeKind::Function),
+            ("app.util.read", GraphNodeKind::Function),
+            ("lib", GraphNodeKind::Module),
+            ("lib.other", GraphNodeKind::Function),
+        ];
+
+        for (name, kind) in nodes {
+            graph.insert_node(
+                name.to_string(),
+                GraphNode {
+                    kind,
+                    file: PathBuf::from("app.py"),
+                    line: 1,
+                },
+            );
+        }
+
+        graph.insert_edge("app", "app.main");
+        graph.insert_edge("app.main", "app.util.load");
+        graph.insert_edge("app.util.load", "app.util.parse");
+        graph.insert_edge("app.util.parse", "app.util.read");
+        graph.insert_edge("lib", "lib.other");
+
+        graph
+    }
+
+    fn node_names(graph: &Graph) -> Vec<&str> {
+        graph.nodes.keys().map(|name| name.as_str()).collect()
+    }
+
+    #[test]
+    fn filter_starts_from_the_module_prefix_and_stops_at_the_depth() {
+        let graph = build_graph().filter(Some("app.main"), Some(2));
+
+        assert_eq!(
+            node_names(&graph),
+            ["app.main", "app.util.load", "app.util.parse"]
+        );
+        assert_eq!(
+            graph.edges.iter().collect::<Vec<_>>(),
+            [
+                &("app.main".to_string(), "app.util.load".to_string()),
+                &("app.util.load".to_string(), "app.util.parse".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_matches_whole_name_parts_only() {
+        let graph = build_graph().filter(Some("app.util"), Some(0));
+
+        assert_eq!(
+            node_names(&graph),
+            [
+                "app.util",
+                "app.util.load",
+                "app.util.parse",
+                "app.util.read"
+            ]
+        );
+        assert!(graph.edges.is_empty());
+
+        assert!(build_graph().filter(Some("ap"), None).nodes.is_empty());
+    }
+
+    #[test]
+    fn filter_without_prefix_starts_from_modules() {
+        let graph = build_graph().filter(None, Some(1));
+
+        assert_eq!(
+            node_names(&graph),
+            ["app", "app.main", "app.util", "lib", "lib.other"]
+        );
+    }
+}
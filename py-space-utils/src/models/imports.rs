@@ -1,12 +1,89 @@
-#[derive(Clone)]
+use rustpython_parser::text_size::TextRange;
+
+use super::{line_index::LineIndex, location::Location};
+
+#[derive(Debug, Clone)]
 pub struct Imports {
     pub path: String,
     pub name: String,
     pub alias: Option<String>,
+    pub level: u32,
+    pub is_from_import: bool,
+    pub is_type_checking: bool,
+    pub is_used: bool,
+    pub module: String,
+    pub range: TextRange,
+    pub location: Location,
+    pub end_location: Location,
 }
 
 impl Imports {
-    pub fn new(path: String, name: String, alias: Option<String>) -> Self {
-        Self { path, name, alias }
+    pub fn new(path: String, name: String, alias: Option<String>, range: TextRange) -> Self {
+        Self {
+            path,
+            name,
+            alias,
+            level: 0,
+            is_from_import: false,
+            is_type_checking: false,
+            is_used: false,
+            module: String::new(),
+            range,
+            location: Location::default(),
+            end_location: Location::default(),
+        }
+    }
+
+    pub fn new_from_import(
+        path: String,
+        name: String,
+        alias: Option<String>,
+        level: u32,
+        range: TextRange,
+    ) -> Self {
+        Self {
+            is_from_import: true,
+            level,
+            ..Self::new(path, name, alias, range)
+        }
+    }
+
+    pub fn bound_name(&self) -> String {
+        if let Some(alias) = &self.alias {
+            return alias.clone();
+        }
+
+        if self.is_from_import {
+            return self.name.clone();
+        }
+
+        match self.path.split(".").next() {
+            Some(first_part) => first_part.to_string(),
+            None => self.path.clone(),
+        }
+    }
+
+    pub fn qualified_name(&self) -> String {
+        if self.module.is_empty() {
+            return self.bound_name();
+        }
+
+        format!("{}.{}", self.module, self.bound_name())
+    }
+
+    /// Spelled out as `import a as a` or `from m import a as a`, the usual way
+    /// to mark a name as re-exported.
+    pub fn is_explicit_reexport(&self) -> bool {
+        let imported_name = match self.is_from_import {
+            true => &self.name,
+            false => &self.path,
+        };
+
+        self.alias.as_ref() == Some(imported_name)
+    }
+
+    pub fn resolve_location(&mut self, line_index: &LineIndex) {
+        self.location = line_index.location(self.range.start());
+        self.end_location = line_index.location(self.range.end());
     }
 }
@@ -0,0 +1,76 @@
+use rustpython_parser::text_size::{TextRange, TextSize};
+
+use super::location::Location;
+
+pub struct LineIndex<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(source: &'a str) -> Self {
+        let mut line_starts = vec![0];
+
+        for (index, byte) in source.bytes().enumerate() {
+            if byte == b'\n' {
+                line_starts.push(index + 1);
+            }
+        }
+
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    pub fn location(&self, offset: TextSize) -> Location {
+        let offset = usize::from(offset).min(self.source.len());
+
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        };
+
+        let line_start = self.line_starts[line];
+        let column = self.source[line_start..offset].chars().count();
+
+        Location::new(line + 1, column + 1)
+    }
+
+    /// Where `name` starts in the `def name` or `class name` inside `range`,
+    /// past any decorators. None when `range` holds no such definition.
+    pub fn find_definition_name(
+        &self,
+        range: TextRange,
+        keyword: &str,
+        name: &str,
+    ) -> Option<TextSize> {
+        let start = usize::from(range.start()).min(self.source.len());
+        let end = usize::from(range.end()).min(self.source.len());
+        let text = self.source.get(start..end)?;
+
+        for (index, _) in text.match_indices(keyword) {
+            let is_word_start = text[..index]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace);
+
+            let rest = &text[index + keyword.len()..];
+            let name_start = rest.trim_start();
+
+            let is_definition = is_word_start
+                && name_start.len() < rest.len()
+                && name_start.starts_with(name)
+                && !name_start[name.len()..]
+                    .starts_with(|next: char| next.is_alphanumeric() || next == '_');
+
+            if is_definition {
+                let offset = start + index + keyword.len() + rest.len() - name_start.len();
+
+                return TextSize::try_from(offset).ok();
+            }
+        }
+
+        None
+    }
+}
@@ -1,5 +1,20 @@
+pub mod argument_def;
+pub mod call_graph;
+pub mod class_def;
+pub mod config;
 pub mod expression_value;
 pub mod file_data;
+pub mod finding;
+pub mod finding_kind;
 pub mod function_def;
+pub mod graph;
 pub mod imports;
+pub mod line_index;
+pub mod location;
+pub mod report;
+pub mod scope;
 pub mod statement_value;
+pub mod suppression;
+pub mod trigger;
+pub mod unreachable_code;
+pub mod variable_def;
@@ -0,0 +1,51 @@
+use std::{collections::HashMap, time::Duration};
+
+use super::{finding::Finding, finding_kind::FindingKind};
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub findings: Vec<Finding>,
+    pub files_scanned: usize,
+    pub files_failed: usize,
+    pub duration: Duration,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self {
+            findings: Vec::new(),
+            files_scanned: 0,
+            files_failed: 0,
+            duration: Duration::ZERO,
+        }
+    }
+
+    /// Orders findings by file, position and qualified name so identical input
+    /// always produces identical output.
+    pub fn sort_findings(&mut self) {
+        self.findings.sort_by(|left, right| {
+            left.file
+                .cmp(&right.file)
+                .then(left.line.cmp(&right.line))
+                .then(left.column.cmp(&right.column))
+                .then(left.qualified_name.cmp(&right.qualified_name))
+                .then(left.kind.cmp(&right.kind))
+        });
+    }
+
+    pub fn count_by_kind(&self) -> HashMap<FindingKind, usize> {
+        let mut counts = HashMap::new();
+
+        for finding in &self.findings {
+            *counts.entry(finding.kind).or_insert(0) += 1;
+        }
+
+        counts
+    }
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self::new()
+    }
+}
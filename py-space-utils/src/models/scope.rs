@@ -0,0 +1,72 @@
+use std::collections::{HashMap, HashSet};
+
+use super::variable_def::VariableDef;
+
+const LOCALS_FUNCTION: &str = "locals";
+
+/// The names one function body binds and reads. Nested functions, lambdas and
+/// comprehensions have their own scope and only show up here through the
+/// names they read from this one.
+#[derive(Debug, Default)]
+pub struct Scope {
+    pub parameters: HashSet<String>,
+    pub assignments: Vec<VariableDef>,
+    pub bound_names: HashSet<String>,
+    pub read_names: HashSet<String>,
+    pub global_names: HashSet<String>,
+    pub nonlocal_names: HashSet<String>,
+}
+
+impl Scope {
+    pub fn is_local(&self, name: &str) -> bool {
+        if self.global_names.contains(name) || self.nonlocal_names.contains(name) {
+            return false;
+        }
+
+        self.parameters.contains(name) || self.bound_names.contains(name)
+    }
+
+    /// Locals that are assigned but never read, each at its first assignment.
+    /// Underscore-prefixed names are meant to be ignored.
+    pub fn unused_locals(&self) -> Vec<VariableDef> {
+        if self.read_names.contains(LOCALS_FUNCTION) {
+            return Vec::new();
+        }
+
+        let mut unused_locals: HashMap<&str, &VariableDef> = HashMap::new();
+
+        for variable in &self.assignments {
+            let name = variable.name.as_str();
+
+            if name.starts_with("_")
+                || !self.is_local(name)
+                || self.parameters.contains(name)
+                || self.read_names.contains(name)
+            {
+                continue;
+            }
+
+            let first = unused_locals.entry(name).or_insert(variable);
+
+            if variable.range.start() < first.range.start() {
+                *first = variable;
+            }
+        }
+
+        let mut unused_locals: Vec<VariableDef> = unused_locals.into_values().cloned().collect();
+        unused_locals.sort_by_key(|variable| variable.range.start());
+
+        unused_locals
+    }
+
+    /// Names read here that belong to an enclosing scope, including the ones
+    /// declared `nonlocal`.
+    pub fn free_names(&self) -> HashSet<String> {
+        self.read_names
+            .iter()
+            .filter(|name| !self.is_local(name))
+            .chain(&self.nonlocal_names)
+            .cloned()
+            .collect()
+    }
+}
@@ -3,14 +3,26 @@ use std::{
     path::PathBuf,
 };
 
-use super::{expression_value::ExpressionValue, file_data::FileData, function_def::FunctionDef};
+use super::{
+    call_graph::CallGraph, class_def::ClassDef, expression_value::ExpressionValue,
+    file_data::FileData, function_def::FunctionDef, line_index::LineIndex,
+    unreachable_code::UnreachableCode, variable_def::VariableDef,
+};
 
 pub struct StatementValue {
     pub functions: HashSet<FunctionDef>,
     pub expression_value: ExpressionValue,
-    pub classes: HashSet<String>,
+    pub classes: HashSet<ClassDef>,
+    pub variables: HashSet<VariableDef>,
+    /// Variables a function declared `global`, kept apart until they reach
+    /// the module so that enclosing functions do not take them for locals.
+    pub global_variables: HashSet<VariableDef>,
     pub assignments: HashMap<String, String>,
     pub files: HashMap<PathBuf, FileData>,
+    pub unreachable_code: Vec<UnreachableCode>,
+    pub global_names: HashSet<String>,
+    pub nonlocal_names: HashSet<String>,
+    pub call_graph: CallGraph,
 }
 
 impl StatementValue {
@@ -19,12 +31,29 @@ impl StatementValue {
             functions: HashSet::new(),
             expression_value: ExpressionValue::new(),
             classes: HashSet::new(),
+            variables: HashSet::new(),
+            global_variables: HashSet::new(),
             assignments: HashMap::new(),
             files: HashMap::new(),
+            unreachable_code: Vec::new(),
+            global_names: HashSet::new(),
+            nonlocal_names: HashSet::new(),
+            call_graph: CallGraph::new(),
         }
     }
 
     pub fn merge_expression_value(&mut self, expression_value: ExpressionValue) {
+        let used_names = expression_value
+            .used_functions
+            .iter()
+            .chain(&expression_value.used_names)
+            .chain(&expression_value.used_attributes)
+            .cloned();
+
+        self.call_graph.insert_module_edges(used_names);
+        self.call_graph
+            .insert_module_calls(expression_value.used_functions.iter().cloned());
+
         self.expression_value
             .merge_expression_result(expression_value);
     }
@@ -33,10 +62,28 @@ impl StatementValue {
         self.functions.insert(value)
     }
 
-    pub fn insert_class(&mut self, value: String) -> bool {
+    pub fn insert_class(&mut self, value: ClassDef) -> bool {
         self.classes.insert(value)
     }
 
+    pub fn insert_variable(&mut self, value: VariableDef) -> bool {
+        self.variables.insert(value)
+    }
+
+    pub fn insert_unreachable_code(&mut self, value: UnreachableCode) {
+        self.unreachable_code.push(value);
+    }
+
+    /// Gives unreachable code found in a function or class body the name of
+    /// the innermost one holding it.
+    pub fn set_unreachable_code_scope(&mut self, scope: &str) {
+        for unreachable_code in &mut self.unreachable_code {
+            if unreachable_code.scope.is_none() {
+                unreachable_code.scope = Some(scope.to_string());
+            }
+        }
+    }
+
     pub fn merge_statement_value(&mut self, statement_value: StatementValue) {
         self.functions.extend(statement_value.functions);
 
@@ -44,19 +91,109 @@ impl StatementValue {
             .merge_expression_result(statement_value.expression_value);
 
         self.classes.extend(statement_value.classes);
+        self.variables.extend(statement_value.variables);
+        self.global_variables
+            .extend(statement_value.global_variables);
         self.assignments.extend(statement_value.assignments);
-        self.files.extend(statement_value.files);
+
+        for file_data in statement_value.files.into_values() {
+            self.merge_file_data(file_data);
+        }
+
+        self.unreachable_code
+            .extend(statement_value.unreachable_code);
+
+        self.global_names.extend(statement_value.global_names);
+        self.nonlocal_names.extend(statement_value.nonlocal_names);
+        self.call_graph.merge_call_graph(statement_value.call_graph);
     }
 
-    pub fn build_full_name_functions(&self) -> HashSet<String> {
-        let mut functions = HashSet::new();
+    pub fn merge_file_data(&mut self, file_data: FileData) {
+        match self.files.get_mut(&file_data.path) {
+            Some(current_file_data) => current_file_data.merge_file_data(file_data),
+            None => {
+                self.files.insert(file_data.path.clone(), file_data);
+            }
+        }
+    }
 
-        for function in self.functions.clone() {
-            let name = function.full_name();
+    pub fn build_qualified_name_functions(&self) -> HashSet<String> {
+        let mut functions = HashSet::new();
 
-            functions.insert(name);
+        for function in &self.functions {
+            functions.insert(function.qualified_name());
         }
 
         functions
     }
+
+    pub fn set_module(&mut self, module: &str) {
+        let functions = std::mem::take(&mut self.functions);
+
+        for mut function in functions {
+            function.module = module.to_string();
+
+            self.functions.insert(function);
+        }
+
+        let classes = std::mem::take(&mut self.classes);
+
+        for mut class in classes {
+            class.module = module.to_string();
+
+            self.classes.insert(class);
+        }
+
+        let variables = std::mem::take(&mut self.variables);
+
+        for mut variable in variables {
+            variable.module = module.to_string();
+
+            self.variables.insert(variable);
+        }
+
+        for file_data in self.files.values_mut() {
+            file_data.set_module(module);
+        }
+
+        for unreachable_code in &mut self.unreachable_code {
+            unreachable_code.module = module.to_string();
+        }
+
+        self.call_graph.set_module(module);
+    }
+
+    pub fn resolve_locations(&mut self, line_index: &LineIndex) {
+        let functions = std::mem::take(&mut self.functions);
+
+        for mut function in functions {
+            function.resolve_location(line_index);
+
+            self.functions.insert(function);
+        }
+
+        let classes = std::mem::take(&mut self.classes);
+
+        for mut class in classes {
+            class.resolve_location(line_index);
+
+            self.classes.insert(class);
+        }
+
+        let variables = std::mem::take(&mut self.variables);
+
+        for mut variable in variables {
+            variable.resolve_location(line_index);
+
+            self.variables.insert(variable);
+        }
+
+        for file_data in self.files.values_mut() {
+            file_data.resolve_locations(line_index);
+        }
+
+        for unreachable_code in &mut self.unreachable_code {
+            unreachable_code.resolve_location(line_index);
+        }
+    }
 }
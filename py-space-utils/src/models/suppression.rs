@@ -0,0 +1,94 @@
+use super::finding_kind::FindingKind;
+
+const NOQA_MARKER: &str = "noqa";
+
+/// A `# noqa` comment, either bare or followed by a list of codes such as
+/// `# noqa: PS004, F401`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Suppression {
+    All,
+    Codes(Vec<String>),
+}
+
+impl Suppression {
+    pub fn from_comment(comment: &str) -> Option<Self> {
+        comment.split('#').find_map(Self::from_segment)
+    }
+
+    fn from_segment(segment: &str) -> Option<Self> {
+        let segment = segment.trim_start();
+
+        let rest = match segment.get(..NOQA_MARKER.len()) {
+            Some(marker) if marker.eq_ignore_ascii_case(NOQA_MARKER) => {
+                &segment[NOQA_MARKER.len()..]
+            }
+            _ => return None,
+        };
+
+        // `# noqa-ish` or `# noqanything` are other words, not the marker.
+        let is_marker_end =
+            rest.is_empty() || rest.starts_with(|next: char| next.is_whitespace() || next == ':');
+
+        if !is_marker_end {
+            return None;
+        }
+
+        let rest = rest.trim_start();
+
+        let codes: Vec<String> = match rest.strip_prefix(':') {
+            Some(value) => value
+                .split([',', ' '])
+                .map(|code| code.trim())
+                .filter(|code| !code.is_empty())
+                .map(|code| code.to_string())
+                .collect(),
+            None => Vec::new(),
+        };
+
+        if codes.is_empty() {
+            return Some(Suppression::All);
+        }
+
+        Some(Suppression::Codes(codes))
+    }
+
+    pub fn covers(&self, kind: FindingKind) -> bool {
+        let codes = match self {
+            Suppression::All => return true,
+            Suppression::Codes(codes) => codes,
+        };
+
+        codes.iter().any(|code| {
+            code.eq_ignore_ascii_case(kind.rule_id())
+                || code == kind.name()
+                || kind.compatible_codes().contains(&code.as_str())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_and_coded_markers() {
+        assert_eq!(Suppression::from_comment("# noqa"), Some(Suppression::All));
+        assert_eq!(
+            Suppression::from_comment("# NOQA: PS004, F401"),
+            Some(Suppression::Codes(vec![
+                "PS004".to_string(),
+                "F401".to_string()
+            ]))
+        );
+        assert_eq!(
+            Suppression::from_comment("# type: ignore # noqa:PS001"),
+            Some(Suppression::Codes(vec!["PS001".to_string()]))
+        );
+    }
+
+    #[test]
+    fn ignores_words_that_only_start_with_the_marker() {
+        assert_eq!(Suppression::from_comment("# noqa-ish"), None);
+        assert_eq!(Suppression::from_comment("# noqanything"), None);
+    }
+}
@@ -0,0 +1,180 @@
+use rustpython_parser::ast;
+
+const OPERATORS: [ast::Operator; 13] = [
+    ast::Operator::Add,
+    ast::Operator::Sub,
+    ast::Operator::Mult,
+    ast::Operator::MatMult,
+    ast::Operator::Div,
+    ast::Operator::Mod,
+    ast::Operator::Pow,
+    ast::Operator::LShift,
+    ast::Operator::RShift,
+    ast::Operator::BitOr,
+    ast::Operator::BitXor,
+    ast::Operator::BitAnd,
+    ast::Operator::FloorDiv,
+];
+
+const COMPARISONS: [ast::CmpOp; 10] = [
+    ast::CmpOp::Eq,
+    ast::CmpOp::NotEq,
+    ast::CmpOp::Lt,
+    ast::CmpOp::LtE,
+    ast::CmpOp::Gt,
+    ast::CmpOp::GtE,
+    ast::CmpOp::Is,
+    ast::CmpOp::IsNot,
+    ast::CmpOp::In,
+    ast::CmpOp::NotIn,
+];
+
+const UNARY_OPERATORS: [ast::UnaryOp; 4] = [
+    ast::UnaryOp::Invert,
+    ast::UnaryOp::Not,
+    ast::UnaryOp::UAdd,
+    ast::UnaryOp::USub,
+];
+
+const CONTEXTS: [ast::ExprContext; 3] = [
+    ast::ExprContext::Load,
+    ast::ExprContext::Store,
+    ast::ExprContext::Del,
+];
+
+/// Syntax that makes Python call a dunder method of the object it works on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trigger {
+    /// `for`, comprehensions, unpacking and `yield from`.
+    Iteration,
+    /// `async for` and async comprehensions.
+    AsyncIteration,
+    With,
+    AsyncWith,
+    Await,
+    /// Conditions of `if`, `while`, `assert`, `and`, `or` and `x if c else y`.
+    Truth,
+    /// Set and dict displays and comprehensions.
+    Hashing,
+    /// f-strings.
+    Format,
+    Call,
+    BinaryOperator(ast::Operator),
+    AugmentedAssignment(ast::Operator),
+    Comparison(ast::CmpOp),
+    UnaryOperator(ast::UnaryOp),
+    Subscript(ast::ExprContext),
+    Attribute(ast::ExprContext),
+}
+
+/// The object a trigger works on, as far as its syntax tells.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Receiver {
+    /// Whatever the dotted name is bound to, `handler` in `handler()`.
+    Name(String),
+    /// An instance of the class named, `Foo()` or `self`.
+    Instance(String),
+    /// A literal or a display, `1` or `[a, b]`, which makes a builtin object.
+    Literal,
+    /// Anything the names do not tell, `items[0]` or a parameter.
+    Unknown,
+}
+
+impl Trigger {
+    /// Every trigger there is, to tell the dunder methods the syntax calls
+    /// from the ones Python calls on its own.
+    pub fn all() -> Vec<Trigger> {
+        let mut triggers = vec![
+            Trigger::Iteration,
+            Trigger::AsyncIteration,
+            Trigger::With,
+            Trigger::AsyncWith,
+            Trigger::Await,
+            Trigger::Truth,
+            Trigger::Hashing,
+            Trigger::Format,
+            Trigger::Call,
+        ];
+
+        triggers.extend(OPERATORS.map(Trigger::BinaryOperator));
+        triggers.extend(OPERATORS.map(Trigger::AugmentedAssignment));
+        triggers.extend(COMPARISONS.map(Trigger::Comparison));
+        triggers.extend(UNARY_OPERATORS.map(Trigger::UnaryOperator));
+        triggers.extend(CONTEXTS.map(Trigger::Subscript));
+        triggers.extend(CONTEXTS.map(Trigger::Attribute));
+
+        triggers
+    }
+
+    /// The dunder methods the syntax may call, reflected and fallback ones
+    /// included: `a + b` calls `b.__radd__` when `a.__add__` gives up.
+    pub fn dunder_methods(self) -> &'static [&'static str] {
+        match self {
+            Trigger::Iteration => &["__iter__", "__next__", "__getitem__"],
+            Trigger::AsyncIteration => &["__aiter__", "__anext__"],
+            Trigger::With => &["__enter__", "__exit__"],
+            Trigger::AsyncWith => &["__aenter__", "__aexit__"],
+            Trigger::Await => &["__await__"],
+            Trigger::Truth => &["__bool__", "__len__"],
+            Trigger::Hashing => &["__hash__", "__eq__"],
+            Trigger::Format => &["__format__", "__str__", "__repr__"],
+            Trigger::Call => &["__call__"],
+            Trigger::BinaryOperator(operator) => match operator {
+                ast::Operator::Add => &["__add__", "__radd__"],
+                ast::Operator::Sub => &["__sub__", "__rsub__"],
+                ast::Operator::Mult => &["__mul__", "__rmul__"],
+                ast::Operator::MatMult => &["__matmul__", "__rmatmul__"],
+                ast::Operator::Div => &["__truediv__", "__rtruediv__"],
+                ast::Operator::Mod => &["__mod__", "__rmod__"],
+                ast::Operator::Pow => &["__pow__", "__rpow__"],
+                ast::Operator::LShift => &["__lshift__", "__rlshift__"],
+                ast::Operator::RShift => &["__rshift__", "__rrshift__"],
+                ast::Operator::BitOr => &["__or__", "__ror__"],
+                ast::Operator::BitXor => &["__xor__", "__rxor__"],
+                ast::Operator::BitAnd => &["__and__", "__rand__"],
+                ast::Operator::FloorDiv => &["__floordiv__", "__rfloordiv__"],
+            },
+            Trigger::AugmentedAssignment(operator) => match operator {
+                ast::Operator::Add => &["__iadd__", "__add__", "__radd__"],
+                ast::Operator::Sub => &["__isub__", "__sub__", "__rsub__"],
+                ast::Operator::Mult => &["__imul__", "__mul__", "__rmul__"],
+                ast::Operator::MatMult => &["__imatmul__", "__matmul__", "__rmatmul__"],
+                ast::Operator::Div => &["__itruediv__", "__truediv__", "__rtruediv__"],
+                ast::Operator::Mod => &["__imod__", "__mod__", "__rmod__"],
+                ast::Operator::Pow => &["__ipow__", "__pow__", "__rpow__"],
+                ast::Operator::LShift => &["__ilshift__", "__lshift__", "__rlshift__"],
+                ast::Operator::RShift => &["__irshift__", "__rshift__", "__rrshift__"],
+                ast::Operator::BitOr => &["__ior__", "__or__", "__ror__"],
+                ast::Operator::BitXor => &["__ixor__", "__xor__", "__rxor__"],
+                ast::Operator::BitAnd => &["__iand__", "__and__", "__rand__"],
+                ast::Operator::FloorDiv => &["__ifloordiv__", "__floordiv__", "__rfloordiv__"],
+            },
+            Trigger::Comparison(operator) => match operator {
+                ast::CmpOp::Eq => &["__eq__"],
+                ast::CmpOp::NotEq => &["__ne__", "__eq__"],
+                ast::CmpOp::Lt => &["__lt__", "__gt__"],
+                ast::CmpOp::LtE => &["__le__", "__ge__"],
+                ast::CmpOp::Gt => &["__gt__", "__lt__"],
+                ast::CmpOp::GtE => &["__ge__", "__le__"],
+                ast::CmpOp::Is | ast::CmpOp::IsNot => &[],
+                ast::CmpOp::In | ast::CmpOp::NotIn => &["__contains__", "__iter__", "__getitem__"],
+            },
+            Trigger::UnaryOperator(operator) => match operator {
+                ast::UnaryOp::Invert => &["__invert__"],
+                ast::UnaryOp::Not => &["__bool__", "__len__"],
+                ast::UnaryOp::UAdd => &["__pos__"],
+                ast::UnaryOp::USub => &["__neg__"],
+            },
+            Trigger::Subscript(context) => match context {
+                ast::ExprContext::Load => &["__getitem__", "__class_getitem__", "__missing__"],
+                ast::ExprContext::Store => &["__setitem__"],
+                ast::ExprContext::Del => &["__delitem__"],
+            },
+            Trigger::Attribute(context) => match context {
+                ast::ExprContext::Load => &["__getattr__", "__getattribute__"],
+                ast::ExprContext::Store => &["__setattr__"],
+                ast::ExprContext::Del => &["__delattr__"],
+            },
+        }
+    }
+}
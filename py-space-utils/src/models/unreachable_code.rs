@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use rustpython_parser::text_size::TextRange;
+
+use super::{line_index::LineIndex, location::Location};
+
+/// A run of statements that can never execute, from the first one to the end
+/// of its block.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct UnreachableCode {
+    pub file: PathBuf,
+    pub module: String,
+    pub scope: Option<String>,
+    pub reason: String,
+    pub range: TextRange,
+    pub location: Location,
+    pub end_location: Location,
+}
+
+impl UnreachableCode {
+    pub fn new(file: PathBuf, reason: String, range: TextRange) -> Self {
+        Self {
+            file,
+            module: String::new(),
+            scope: None,
+            reason,
+            range,
+            location: Location::default(),
+            end_location: Location::default(),
+        }
+    }
+
+    /// The module, followed by the function or class holding the code.
+    pub fn qualified_name(&self) -> String {
+        match (&self.scope, self.module.is_empty()) {
+            (Some(scope), true) => scope.clone(),
+            (Some(scope), false) => format!("{}.{}", self.module, scope),
+            (None, _) => self.module.clone(),
+        }
+    }
+
+    pub fn resolve_location(&mut self, line_index: &LineIndex) {
+        self.location = line_index.location(self.range.start());
+        self.end_location = line_index.location(self.range.end());
+    }
+}
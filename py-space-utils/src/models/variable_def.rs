@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use rustpython_parser::text_size::TextRange;
+
+use super::{line_index::LineIndex, location::Location};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct VariableDef {
+    pub name: String,
+    pub file: PathBuf,
+    pub class_name: Option<String>,
+    pub module: String,
+    pub range: TextRange,
+    pub location: Location,
+    pub end_location: Location,
+}
+
+impl VariableDef {
+    pub fn new(name: String, file: PathBuf, class_name: Option<String>, range: TextRange) -> Self {
+        Self {
+            name,
+            file,
+            class_name,
+            module: String::new(),
+            range,
+            location: Location::default(),
+            end_location: Location::default(),
+        }
+    }
+
+    pub fn full_name(&self) -> String {
+        match &self.class_name {
+            Some(class_name) => format!("{}.{}", class_name, self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    pub fn qualified_name(&self) -> String {
+        if self.module.is_empty() {
+            return self.full_name();
+        }
+
+        format!("{}.{}", self.module, self.full_name())
+    }
+
+    pub fn resolve_location(&mut self, line_index: &LineIndex) {
+        self.location = line_index.location(self.range.start());
+        self.end_location = line_index.location(self.range.end());
+    }
+}
@@ -1,33 +1,97 @@
-use std::path::PathBuf;
-
-use crate::models::{expression_value::ExpressionValue, function_def::FunctionDef};
-use rustpython_parser::ast;
+use std::{collections::HashSet, path::PathBuf};
+
+use crate::{
+    class_hierarchy::SUPER_CALL,
+    models::{
+        expression_value::ExpressionValue,
+        function_def::FunctionDef,
+        trigger::{Receiver, Trigger},
+    },
+};
+use log::trace;
+use rustpython_parser::{Parse, ast};
 
 const POSSIBLE_FUNCTIONS_IGNORE: [&str; 1] = ["staticmethod"];
-
-fn extract_inner_caller_id(value: ast::ExprAttribute) -> Option<String> {
-    let id = match *value.value {
-        ast::Expr::Call(inner_call) => match *inner_call.func {
-            ast::Expr::Name(inner_name) => inner_name.id.to_string(),
-            _ => return None,
-        },
-        ast::Expr::Name(inner_name) => inner_name.id.to_string(),
-        _ => return None,
+const SELF_REFERENCES: [&str; 2] = ["self", "cls"];
+const INSTANCE_REFERENCE: &str = "self";
+const SUPER_REFERENCE: &str = "super";
+const LITERAL_ANNOTATION: &str = "Literal";
+
+fn replace_self_reference(name: String, class_name: &Option<String>) -> String {
+    let class_value = match class_name {
+        Some(value) => value,
+        None => return name,
     };
 
-    Some(format!("{}.{}", id, value.attr))
+    match name.split_once(".") {
+        Some((first_part, rest)) if SELF_REFERENCES.contains(&first_part) => {
+            format!("{}.{}", class_value, rest)
+        }
+        Some((SUPER_REFERENCE, rest)) => format!("{}.{}.{}", class_value, SUPER_CALL, rest),
+        _ => name,
+    }
 }
 
-fn extract_called_function_id(data: ast::ExprCall) -> Option<String> {
-    let func_value = *data.func;
+/// The dotted name an attribute is read from: `a.b` in `a.b.c`, and the
+/// class in `Foo().c` since that reads from an instance of it.
+fn get_receiver_name(expression: &ast::Expr) -> Option<String> {
+    match expression {
+        ast::Expr::Call(value) => get_dotted_name(&value.func),
+        _ => get_dotted_name(expression),
+    }
+}
 
-    match func_value {
+/// The dotted name of a chain of attributes, looking through the calls in it:
+/// `Foo.save` for `Foo().save`.
+pub fn get_attribute_chain(expression: &ast::Expr) -> Option<String> {
+    match expression {
         ast::Expr::Name(value) => Some(value.id.to_string()),
-        ast::Expr::Attribute(value) => extract_inner_caller_id(value),
+        ast::Expr::Attribute(value) => {
+            get_attribute_chain(&value.value).map(|name| format!("{}.{}", name, value.attr))
+        }
+        ast::Expr::Call(value) => get_attribute_chain(&value.func),
         _ => None,
     }
 }
 
+/// What `expression` evaluates to, as far as names tell: the object a name is
+/// bound to, or an instance of the class a call creates. `self` is an
+/// instance of the class around it.
+pub fn get_receiver(expression: &ast::Expr, class_name: &Option<String>) -> Receiver {
+    if let (ast::Expr::Name(value), Some(class_value)) = (expression, class_name)
+        && SELF_REFERENCES.contains(&value.id.as_str())
+    {
+        return match value.id.as_str() == INSTANCE_REFERENCE {
+            true => Receiver::Instance(class_value.clone()),
+            false => Receiver::Name(class_value.clone()),
+        };
+    }
+
+    let (chain, is_instance) = match expression {
+        ast::Expr::Constant(_)
+        | ast::Expr::JoinedStr(_)
+        | ast::Expr::List(_)
+        | ast::Expr::Tuple(_)
+        | ast::Expr::Dict(_)
+        | ast::Expr::Set(_)
+        | ast::Expr::ListComp(_)
+        | ast::Expr::SetComp(_)
+        | ast::Expr::DictComp(_)
+        | ast::Expr::GeneratorExp(_) => return Receiver::Literal,
+        ast::Expr::Call(value) => (get_attribute_chain(&value.func), true),
+        _ => (get_attribute_chain(expression), false),
+    };
+
+    let Some(name) = chain.map(|name| replace_self_reference(name, class_name)) else {
+        return Receiver::Unknown;
+    };
+
+    match is_instance {
+        true => Receiver::Instance(name),
+        false => Receiver::Name(name),
+    }
+}
+
 fn extract_from_generators(
     generators: &Vec<ast::Comprehension>,
     path: &PathBuf,
@@ -36,6 +100,13 @@ fn extract_from_generators(
     let mut expression_value = ExpressionValue::new();
 
     for generator in generators {
+        let trigger = match generator.is_async {
+            true => Trigger::AsyncIteration,
+            false => Trigger::Iteration,
+        };
+
+        expression_value.insert_trigger(trigger, get_receiver(&generator.iter, &class_name));
+
         expression_value.merge_expression_result(handle_expression(
             &generator.target,
             path,
@@ -60,6 +131,46 @@ fn extract_from_generators(
     expression_value
 }
 
+/// Comprehension targets are bound in the comprehension's own scope, so reads
+/// of them never reach the enclosing function. The first iterable is
+/// evaluated outside that scope.
+fn bind_comprehension_targets(
+    expression_value: &mut ExpressionValue,
+    generators: &[ast::Comprehension],
+    path: &PathBuf,
+    class_name: Option<String>,
+) {
+    for generator in generators {
+        let target_value = handle_expression(&generator.target, path, class_name.clone());
+
+        for name in &target_value.assigned_names {
+            expression_value.free_names.remove(name);
+        }
+    }
+
+    if let Some(generator) = generators.first() {
+        expression_value
+            .free_names
+            .extend(handle_expression(&generator.iter, path, class_name).free_names);
+    }
+}
+
+/// Names of every parameter, including `*args` and `**kwargs`.
+pub fn get_argument_names(arguments: &ast::Arguments) -> HashSet<String> {
+    let args_with_default = arguments
+        .posonlyargs
+        .iter()
+        .chain(&arguments.args)
+        .chain(&arguments.kwonlyargs)
+        .map(|arg| &arg.def);
+
+    args_with_default
+        .chain(arguments.vararg.as_deref())
+        .chain(arguments.kwarg.as_deref())
+        .map(|arg| arg.arg.to_string())
+        .collect()
+}
+
 pub fn handle_expression(
     expression: &ast::Expr,
     path: &PathBuf,
@@ -67,23 +178,25 @@ pub fn handle_expression(
 ) -> ExpressionValue {
     let mut expression_value = ExpressionValue::new();
 
-    dbg!(&expression);
-
     match expression {
         ast::Expr::Call(value) => {
-            let name = extract_called_function_id(value.clone());
+            expression_value.insert_trigger(Trigger::Call, get_receiver(&value.func, &class_name));
 
-            if name.is_some() {
-                let name_value = name.unwrap();
-                let full_name = match class_name.clone() {
-                    Some(class_value) => class_value + "." + &name_value,
-                    None => name_value,
-                };
+            if let Some(name_value) = get_attribute_chain(&value.func) {
+                let full_name = replace_self_reference(name_value, &class_name);
 
                 expression_value.insert(full_name.clone());
                 expression_value.insert_name(full_name);
             }
 
+            // The callee is read too: `self` in `self.save()`, `HANDLERS` and
+            // `key` in `HANDLERS[key]()`, and `factory` in `factory()()`.
+            expression_value.merge_expression_result(handle_expression(
+                &value.func,
+                path,
+                class_name.clone(),
+            ));
+
             for arg in &value.args {
                 expression_value.merge_expression_result(handle_expression(
                     &arg,
@@ -103,6 +216,8 @@ pub fn handle_expression(
 
         ast::Expr::BoolOp(value) => {
             for data in &value.values {
+                expression_value.insert_trigger(Trigger::Truth, get_receiver(data, &class_name));
+
                 expression_value.merge_expression_result(handle_expression(
                     data,
                     path,
@@ -125,6 +240,14 @@ pub fn handle_expression(
             ));
         }
         ast::Expr::BinOp(value) => {
+            // `a + b` may call `b.__radd__` as well as `a.__add__`.
+            for operand in [&value.left, &value.right] {
+                expression_value.insert_trigger(
+                    Trigger::BinaryOperator(value.op),
+                    get_receiver(operand, &class_name),
+                );
+            }
+
             expression_value.merge_expression_result(handle_expression(
                 &value.left,
                 path,
@@ -138,6 +261,11 @@ pub fn handle_expression(
             ));
         }
         ast::Expr::UnaryOp(value) => {
+            expression_value.insert_trigger(
+                Trigger::UnaryOperator(value.op),
+                get_receiver(&value.operand, &class_name),
+            );
+
             expression_value.merge_expression_result(handle_expression(
                 &value.operand,
                 path,
@@ -150,8 +278,14 @@ pub fn handle_expression(
                 path,
                 class_name.clone(),
             ));
+
+            for name in get_argument_names(&value.args) {
+                expression_value.free_names.remove(&name);
+            }
         }
         ast::Expr::IfExp(value) => {
+            expression_value.insert_trigger(Trigger::Truth, get_receiver(&value.test, &class_name));
+
             expression_value.merge_expression_result(handle_expression(
                 &value.test,
                 path,
@@ -177,6 +311,11 @@ pub fn handle_expression(
                     continue;
                 }
 
+                expression_value.insert_trigger(
+                    Trigger::Hashing,
+                    get_receiver(key.as_ref().unwrap(), &class_name),
+                );
+
                 expression_value.merge_expression_result(handle_expression(
                     &key.clone().unwrap(),
                     path,
@@ -195,6 +334,9 @@ pub fn handle_expression(
 
         ast::Expr::Set(value) => {
             for element in &value.elts {
+                expression_value
+                    .insert_trigger(Trigger::Hashing, get_receiver(element, &class_name));
+
                 expression_value.merge_expression_result(handle_expression(
                     &element,
                     path,
@@ -215,8 +357,18 @@ pub fn handle_expression(
                 path,
                 class_name.clone(),
             ));
+
+            bind_comprehension_targets(
+                &mut expression_value,
+                &value.generators,
+                path,
+                class_name.clone(),
+            );
         }
         ast::Expr::SetComp(value) => {
+            expression_value
+                .insert_trigger(Trigger::Hashing, get_receiver(&value.elt, &class_name));
+
             expression_value.merge_expression_result(handle_expression(
                 &value.elt,
                 path,
@@ -228,8 +380,18 @@ pub fn handle_expression(
                 path,
                 class_name.clone(),
             ));
+
+            bind_comprehension_targets(
+                &mut expression_value,
+                &value.generators,
+                path,
+                class_name.clone(),
+            );
         }
         ast::Expr::DictComp(value) => {
+            expression_value
+                .insert_trigger(Trigger::Hashing, get_receiver(&value.key, &class_name));
+
             expression_value.merge_expression_result(handle_expression(
                 &value.key,
                 path,
@@ -247,6 +409,13 @@ pub fn handle_expression(
                 path,
                 class_name.clone(),
             ));
+
+            bind_comprehension_targets(
+                &mut expression_value,
+                &value.generators,
+                path,
+                class_name.clone(),
+            );
         }
 
         ast::Expr::GeneratorExp(value) => {
@@ -261,9 +430,19 @@ pub fn handle_expression(
                 path,
                 class_name.clone(),
             ));
+
+            bind_comprehension_targets(
+                &mut expression_value,
+                &value.generators,
+                path,
+                class_name.clone(),
+            );
         }
 
         ast::Expr::Await(value) => {
+            expression_value
+                .insert_trigger(Trigger::Await, get_receiver(&value.value, &class_name));
+
             expression_value.merge_expression_result(handle_expression(
                 &value.value,
                 path,
@@ -282,6 +461,9 @@ pub fn handle_expression(
         }
 
         ast::Expr::YieldFrom(value) => {
+            expression_value
+                .insert_trigger(Trigger::Iteration, get_receiver(&value.value, &class_name));
+
             expression_value.merge_expression_result(handle_expression(
                 &value.value,
                 path,
@@ -290,6 +472,21 @@ pub fn handle_expression(
         }
 
         ast::Expr::Compare(value) => {
+            // Each comparison may call a method of either side, `__lt__` of
+            // the left one or the reflected `__gt__` of the right one.
+            let operands: Vec<&ast::Expr> = std::iter::once(value.left.as_ref())
+                .chain(&value.comparators)
+                .collect();
+
+            for (index, operator) in value.ops.iter().enumerate() {
+                for operand in &operands[index..index + 2] {
+                    expression_value.insert_trigger(
+                        Trigger::Comparison(*operator),
+                        get_receiver(operand, &class_name),
+                    );
+                }
+            }
+
             expression_value.merge_expression_result(handle_expression(
                 &value.left,
                 path,
@@ -306,6 +503,9 @@ pub fn handle_expression(
         }
 
         ast::Expr::FormattedValue(value) => {
+            expression_value
+                .insert_trigger(Trigger::Format, get_receiver(&value.value, &class_name));
+
             expression_value.merge_expression_result(handle_expression(
                 &value.value,
                 path,
@@ -333,20 +533,33 @@ pub fn handle_expression(
 
         ast::Expr::Constant(_value) => {}
         ast::Expr::Attribute(value) => {
-            let current_value = handle_expression(&value.value, path, class_name.clone());
-            let used_names = current_value.used_names.clone();
-            expression_value.merge_expression_result(current_value);
+            expression_value.insert_trigger(
+                Trigger::Attribute(value.ctx),
+                get_receiver(&value.value, &class_name),
+            );
 
-            let first_name = used_names.iter().next();
+            expression_value.merge_expression_result(handle_expression(
+                &value.value,
+                path,
+                class_name.clone(),
+            ));
 
-            if first_name.is_some() {
-                let full_name = format!("{}.{}", first_name.unwrap(), value.attr);
+            if let Some(receiver_name) = get_receiver_name(&value.value) {
+                let full_name = replace_self_reference(
+                    format!("{}.{}", receiver_name, value.attr),
+                    &class_name,
+                );
 
                 expression_value.insert_attribute(full_name);
             }
         }
 
         ast::Expr::Subscript(value) => {
+            expression_value.insert_trigger(
+                Trigger::Subscript(value.ctx),
+                get_receiver(&value.value, &class_name),
+            );
+
             expression_value.merge_expression_result(handle_expression(
                 &value.value,
                 path,
@@ -361,6 +574,9 @@ pub fn handle_expression(
         }
 
         ast::Expr::Starred(value) => {
+            expression_value
+                .insert_trigger(Trigger::Iteration, get_receiver(&value.value, &class_name));
+
             expression_value.merge_expression_result(handle_expression(
                 &value.value,
                 path,
@@ -370,17 +586,34 @@ pub fn handle_expression(
 
         ast::Expr::Name(value) => {
             let name = value.id.to_string();
-            expression_value.insert_name(name.clone());
 
-            if !POSSIBLE_FUNCTIONS_IGNORE.contains(&name.as_str()) {
+            match value.ctx {
+                ast::ExprContext::Store => expression_value.insert_assigned_name(name.clone()),
+                _ => {
+                    expression_value.insert_free_name(name.clone());
+                    expression_value.insert_name(name.clone())
+                }
+            };
+
+            // A name that is read rather than called may be a function passed
+            // around as a value.
+            let is_read = value.ctx == ast::ExprContext::Load;
+
+            if is_read && !POSSIBLE_FUNCTIONS_IGNORE.contains(&name.as_str()) {
                 expression_value.insert_possible_function(FunctionDef::new(
                     name,
                     path.to_path_buf(),
                     class_name.clone(),
+                    value.range,
                 ));
             }
         }
         ast::Expr::List(value) => {
+            // `a, b = pair` iterates over `pair`.
+            if value.ctx == ast::ExprContext::Store {
+                expression_value.insert_trigger(Trigger::Iteration, Receiver::Unknown);
+            }
+
             for inner_value in &value.elts {
                 expression_value.merge_expression_result(handle_expression(
                     &inner_value,
@@ -391,6 +624,10 @@ pub fn handle_expression(
         }
 
         ast::Expr::Tuple(value) => {
+            if value.ctx == ast::ExprContext::Store {
+                expression_value.insert_trigger(Trigger::Iteration, Receiver::Unknown);
+            }
+
             for inner_value in &value.elts {
                 expression_value.merge_expression_result(handle_expression(
                     &inner_value,
@@ -428,3 +665,83 @@ pub fn handle_expression(
 
     expression_value
 }
+
+/// `a.b.c` for a chain of names and attributes, looking through subscripts so
+/// `Generic[T]` gives `Generic`.
+pub fn get_dotted_name(expression: &ast::Expr) -> Option<String> {
+    match expression {
+        ast::Expr::Name(value) => Some(value.id.to_string()),
+        ast::Expr::Attribute(value) => {
+            get_dotted_name(&value.value).map(|name| format!("{}.{}", name, value.attr))
+        }
+        ast::Expr::Subscript(value) => get_dotted_name(&value.value),
+        _ => None,
+    }
+}
+
+fn is_literal_annotation(expression: &ast::Expr) -> bool {
+    match expression {
+        ast::Expr::Name(value) => value.id.as_str() == LITERAL_ANNOTATION,
+        ast::Expr::Attribute(value) => value.attr.as_str() == LITERAL_ANNOTATION,
+        _ => false,
+    }
+}
+
+fn collect_string_annotations(expression: &ast::Expr, annotations: &mut Vec<String>) {
+    match expression {
+        ast::Expr::Constant(value) => {
+            if let ast::Constant::Str(text) = &value.value {
+                annotations.push(text.clone());
+            }
+        }
+        ast::Expr::Subscript(value) => {
+            if is_literal_annotation(&value.value) {
+                return;
+            }
+
+            collect_string_annotations(&value.slice, annotations);
+        }
+        ast::Expr::BinOp(value) => {
+            collect_string_annotations(&value.left, annotations);
+            collect_string_annotations(&value.right, annotations);
+        }
+        ast::Expr::Tuple(value) => {
+            for element in &value.elts {
+                collect_string_annotations(element, annotations);
+            }
+        }
+        ast::Expr::List(value) => {
+            for element in &value.elts {
+                collect_string_annotations(element, annotations);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Like `handle_expression`, but also reads forward references such as
+/// `"Foo"` or `Optional["Foo"]` so names only used in quoted annotations
+/// count as used.
+pub fn handle_annotation(
+    expression: &ast::Expr,
+    path: &PathBuf,
+    class_name: Option<String>,
+) -> ExpressionValue {
+    let mut expression_value = handle_expression(expression, path, class_name.clone());
+    let mut annotations = Vec::new();
+
+    collect_string_annotations(expression, &mut annotations);
+
+    for annotation in annotations {
+        match ast::Expr::parse(annotation.trim(), "<annotation>") {
+            Ok(value) => expression_value.merge_expression_result(handle_annotation(
+                &value,
+                path,
+                class_name.clone(),
+            )),
+            Err(error) => trace!("ignoring string annotation {:?}: {}", annotation, error),
+        }
+    }
+
+    expression_value
+}
@@ -1,21 +1,262 @@
-use rustpython_parser::ast;
-use rustpython_parser::{parse, Mode};
+use rustpython_parser::ast::{self, Ranged};
+use rustpython_parser::lexer::lex;
+use rustpython_parser::text_size::TextRange;
+use rustpython_parser::{parse, Mode, Tok};
 use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::errors::py_space::PySpaceError;
+use crate::models::argument_def::ArgumentDef;
+use crate::models::class_def::ClassDef;
+use crate::models::expression_value::ExpressionValue;
 use crate::models::file_data::FileData;
 use crate::models::function_def::FunctionDef;
 use crate::models::imports::Imports;
+use crate::models::line_index::LineIndex;
+use crate::models::scope::Scope;
 use crate::models::statement_value::StatementValue;
-use crate::parse_expression::handle_expression;
+use crate::models::suppression::Suppression;
+use crate::models::trigger::Trigger;
+use crate::models::unreachable_code::UnreachableCode;
+use crate::models::variable_def::VariableDef;
+use crate::parse_expression::{
+    get_argument_names, get_attribute_chain, get_dotted_name, get_receiver, handle_annotation,
+    handle_expression,
+};
+
+const TYPE_CHECKING: &str = "TYPE_CHECKING";
+const ALL_EXPORTS: &str = "__all__";
+const NOT_IMPLEMENTED_ERROR: &str = "NotImplementedError";
+const LOCALS_FUNCTION: &str = "locals";
 
 enum FunctionDefOptions {
     StmtFunctionDef(ast::StmtFunctionDef),
     StmtAsyncFunctionDef(ast::StmtAsyncFunctionDef),
 }
 
+fn handle_arguments(
+    arguments: &ast::Arguments,
+    path: &PathBuf,
+    class_name: Option<String>,
+) -> ExpressionValue {
+    let mut expression_value = ExpressionValue::new();
+
+    let args_with_default = arguments
+        .posonlyargs
+        .iter()
+        .chain(&arguments.args)
+        .chain(&arguments.kwonlyargs);
+
+    let mut annotations = Vec::new();
+
+    for arg in args_with_default {
+        annotations.extend(arg.def.annotation.as_deref());
+
+        if let Some(default) = &arg.default {
+            expression_value.merge_expression_result(handle_expression(
+                default,
+                path,
+                class_name.clone(),
+            ));
+        }
+    }
+
+    for arg in arguments.vararg.iter().chain(&arguments.kwarg) {
+        annotations.extend(arg.annotation.as_deref());
+    }
+
+    for annotation in annotations {
+        expression_value.merge_expression_result(handle_annotation(
+            annotation,
+            path,
+            class_name.clone(),
+        ));
+    }
+
+    expression_value
+}
+
+fn get_arguments(arguments: &ast::Arguments) -> Vec<ArgumentDef> {
+    let args_with_default = arguments
+        .posonlyargs
+        .iter()
+        .chain(&arguments.args)
+        .map(|arg| &arg.def);
+
+    let kwonlyargs = arguments.kwonlyargs.iter().map(|arg| &arg.def);
+
+    args_with_default
+        .chain(arguments.vararg.as_deref())
+        .chain(kwonlyargs)
+        .chain(arguments.kwarg.as_deref())
+        .map(|arg| ArgumentDef::new(arg.arg.to_string(), arg.range))
+        .collect()
+}
+
+fn is_argument_read(name: &str, read_names: &HashSet<String>) -> bool {
+    let prefix = format!("{}.", name);
+
+    read_names
+        .iter()
+        .any(|read_name| read_name == name || read_name.starts_with(&prefix))
+}
+
+fn is_not_implemented_error(expression: &ast::Expr) -> bool {
+    let expression = match expression {
+        ast::Expr::Call(value) => value.func.as_ref(),
+        _ => expression,
+    };
+
+    matches!(expression, ast::Expr::Name(value) if value.id.as_str() == NOT_IMPLEMENTED_ERROR)
+}
+
+/// A body made only of a docstring, `...`, `pass` or
+/// `raise NotImplementedError`.
+fn is_stub_body(body: &[ast::Stmt]) -> bool {
+    body.iter().all(|statement| match statement {
+        ast::Stmt::Pass(_) => true,
+        ast::Stmt::Expr(value) => matches!(value.value.as_ref(), ast::Expr::Constant(_)),
+        ast::Stmt::Raise(value) => value.exc.as_deref().is_some_and(is_not_implemented_error),
+        _ => false,
+    })
+}
+
+/// Calls that never return, by the name they are called with.
+const EXIT_FUNCTIONS: [&str; 5] = ["sys.exit", "exit", "quit", "os._exit", "os.abort"];
+
+/// The truth value of a condition written as a literal, like `while True` or
+/// `if 0`.
+fn get_constant_test(test: &ast::Expr) -> Option<bool> {
+    match test {
+        ast::Expr::Constant(value) => match &value.value {
+            ast::Constant::Bool(value) => Some(*value),
+            ast::Constant::None => Some(false),
+            ast::Constant::Int(value) => Some(value.to_string() != "0"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn get_exit_function(expression: &ast::Expr) -> Option<String> {
+    let ast::Expr::Call(value) = expression else {
+        return None;
+    };
+
+    get_dotted_name(&value.func).filter(|name| EXIT_FUNCTIONS.contains(&name.as_str()))
+}
+
+/// Whether a `break` in `body` leaves the loop `body` belongs to. Breaks in
+/// the body of nested loops and in functions only leave those, while the
+/// `else` of a nested loop still belongs to the outer one.
+fn has_break(body: &[ast::Stmt]) -> bool {
+    body.iter().any(|statement| match statement {
+        ast::Stmt::Break(_) => true,
+        ast::Stmt::For(value) => has_break(&value.orelse),
+        ast::Stmt::AsyncFor(value) => has_break(&value.orelse),
+        ast::Stmt::While(value) => has_break(&value.orelse),
+        ast::Stmt::If(value) => has_break(&value.body) || has_break(&value.orelse),
+        ast::Stmt::With(value) => has_break(&value.body),
+        ast::Stmt::AsyncWith(value) => has_break(&value.body),
+        ast::Stmt::Match(value) => value.cases.iter().any(|case| has_break(&case.body)),
+        ast::Stmt::Try(value) => {
+            has_break(&value.body)
+                || has_break(&value.orelse)
+                || has_break(&value.finalbody)
+                || value.handlers.iter().any(|handler| match handler {
+                    ast::ExceptHandler::ExceptHandler(handler) => has_break(&handler.body),
+                })
+        }
+        ast::Stmt::TryStar(value) => {
+            has_break(&value.body)
+                || has_break(&value.orelse)
+                || has_break(&value.finalbody)
+                || value.handlers.iter().any(|handler| match handler {
+                    ast::ExceptHandler::ExceptHandler(handler) => has_break(&handler.body),
+                })
+        }
+        _ => false,
+    })
+}
+
+/// Why the statements following `statement` in its block can never run, if
+/// they can't.
+fn get_exit_reason(statement: &ast::Stmt) -> Option<String> {
+    match statement {
+        ast::Stmt::Return(_) => Some("code after `return` is never executed".to_string()),
+        ast::Stmt::Raise(_) => Some("code after `raise` is never executed".to_string()),
+        ast::Stmt::Break(_) => Some("code after `break` is never executed".to_string()),
+        ast::Stmt::Continue(_) => Some("code after `continue` is never executed".to_string()),
+        ast::Stmt::Expr(value) => get_exit_function(&value.value)
+            .map(|name| format!("code after `{}()` is never executed", name)),
+        ast::Stmt::While(value)
+            if get_constant_test(&value.test) == Some(true) && !has_break(&value.body) =>
+        {
+            Some("code after an infinite loop is never executed".to_string())
+        }
+        ast::Stmt::If(value) => match get_constant_test(&value.test) {
+            Some(true) => get_block_exit_reason(&value.body),
+            Some(false) => get_block_exit_reason(&value.orelse),
+            None => {
+                let is_exiting = get_block_exit_reason(&value.body).is_some()
+                    && get_block_exit_reason(&value.orelse).is_some();
+
+                is_exiting.then(|| {
+                    "code after an `if` whose branches all exit is never executed".to_string()
+                })
+            }
+        },
+        _ => None,
+    }
+}
+
+fn get_block_exit_reason(body: &[ast::Stmt]) -> Option<String> {
+    body.iter().find_map(get_exit_reason)
+}
+
+fn get_block_range(body: &[ast::Stmt]) -> Option<TextRange> {
+    match (body.first(), body.last()) {
+        (Some(first), Some(last)) => Some(TextRange::new(first.start(), last.end())),
+        _ => None,
+    }
+}
+
+fn insert_unreachable_block(
+    statement_value: &mut StatementValue,
+    body: &[ast::Stmt],
+    path: &Path,
+    reason: &str,
+) {
+    if let Some(range) = get_block_range(body) {
+        statement_value.insert_unreachable_code(UnreachableCode::new(
+            path.to_path_buf(),
+            reason.to_string(),
+            range,
+        ));
+    }
+}
+
+/// Records the statements of `body` that follow one that always exits the
+/// block.
+fn insert_unreachable_code(statement_value: &mut StatementValue, body: &[ast::Stmt], path: &Path) {
+    for (index, statement) in body.iter().enumerate() {
+        if let Some(reason) = get_exit_reason(statement) {
+            insert_unreachable_block(statement_value, &body[index + 1..], path, &reason);
+
+            return;
+        }
+    }
+}
+
+/// `app.route` for both `@app.route` and `@app.route("/")`.
+fn get_decorator_name(decorator: &ast::Expr) -> Option<String> {
+    match decorator {
+        ast::Expr::Call(value) => get_dotted_name(&value.func),
+        _ => get_dotted_name(decorator),
+    }
+}
+
 fn handle_function_def(
     data: FunctionDefOptions,
     path: &PathBuf,
@@ -23,29 +264,109 @@ fn handle_function_def(
 ) -> StatementValue {
     let mut statement_value = StatementValue::new();
 
-    let (body, name, decorator_list) = match data {
-        FunctionDefOptions::StmtFunctionDef(value) => {
-            (value.body, value.name, value.decorator_list)
-        }
-        FunctionDefOptions::StmtAsyncFunctionDef(value) => {
-            (value.body, value.name, value.decorator_list)
-        }
+    let (body, name, args, returns, decorator_list, range) = match data {
+        FunctionDefOptions::StmtFunctionDef(value) => (
+            value.body,
+            value.name,
+            value.args,
+            value.returns,
+            value.decorator_list,
+            value.range,
+        ),
+        FunctionDefOptions::StmtAsyncFunctionDef(value) => (
+            value.body,
+            value.name,
+            value.args,
+            value.returns,
+            value.decorator_list,
+            value.range,
+        ),
     };
 
-    statement_value.insert_function(FunctionDef::new(
+    let mut function_def = FunctionDef::new(
         name.to_string(),
         path.to_path_buf(),
         class_name.clone(),
-    ));
+        range,
+    );
 
-    for statement in body {
-        statement_value.merge_statement_value(handle_statement(
-            statement,
+    function_def.is_stub = is_stub_body(&body);
+    function_def.decorators = decorator_list.iter().filter_map(get_decorator_name).collect();
+
+    statement_value.merge_expression_value(handle_arguments(&args, path, class_name.clone()));
+
+    if let Some(returns) = returns {
+        statement_value.merge_expression_value(handle_annotation(
+            &returns,
             path,
             class_name.clone(),
         ));
     }
 
+    let mut body_value = StatementValue::new();
+
+    insert_unreachable_code(&mut body_value, &body, path);
+
+    for statement in body {
+        body_value.merge_statement_value(handle_statement(statement, path, class_name.clone()));
+    }
+
+    let body_expression_value = &body_value.expression_value;
+
+    let read_names: HashSet<String> = body_expression_value
+        .used_names
+        .iter()
+        .chain(&body_expression_value.used_functions)
+        .chain(&body_expression_value.used_attributes)
+        .cloned()
+        .collect();
+
+    if !read_names.contains(LOCALS_FUNCTION) {
+        function_def.unused_arguments = get_arguments(&args)
+            .into_iter()
+            .filter(|argument| !is_argument_read(&argument.name, &read_names))
+            .collect();
+    }
+
+    let mut scope = Scope {
+        parameters: get_argument_names(&args),
+        assignments: Vec::new(),
+        bound_names: body_value.expression_value.assigned_names.clone(),
+        read_names: std::mem::take(&mut body_value.expression_value.free_names),
+        global_names: std::mem::take(&mut body_value.global_names),
+        nonlocal_names: std::mem::take(&mut body_value.nonlocal_names),
+    };
+
+    // Names assigned in a function body are locals, not module or class
+    // variables, unless they were declared `global`.
+    for variable in std::mem::take(&mut body_value.variables) {
+        if variable.class_name != class_name {
+            continue;
+        }
+
+        if scope.global_names.contains(&variable.name) {
+            body_value.global_variables.insert(VariableDef::new(
+                variable.name,
+                variable.file,
+                None,
+                variable.range,
+            ));
+        } else {
+            scope.assignments.push(variable);
+        }
+    }
+
+    function_def.unused_locals = scope.unused_locals();
+    body_value.expression_value.free_names = scope.free_names();
+    body_value
+        .call_graph
+        .attribute_module_edges(&function_def.full_name());
+
+    statement_value.merge_statement_value(body_value);
+
+    statement_value.set_unreachable_code_scope(&function_def.full_name());
+    statement_value.insert_function(function_def);
+
     for decorator in decorator_list {
         let new_used_functions = handle_expression(&decorator, path, class_name.clone());
 
@@ -57,20 +378,149 @@ fn handle_function_def(
 
 fn handle_class_def(value: ast::StmtClassDef, path: &PathBuf) -> StatementValue {
     let mut statement_value = StatementValue::new();
+    let class_name = value.name.to_string();
+
+    let mut class_def = ClassDef::new(class_name.clone(), path.to_path_buf(), value.range);
+
+    class_def.bases = value.bases.iter().filter_map(get_dotted_name).collect();
+
+    statement_value.insert_class(class_def);
+
+    for base in &value.bases {
+        statement_value.merge_expression_value(handle_expression(base, path, None));
+    }
+
+    for keyword in &value.keywords {
+        statement_value.merge_expression_value(handle_expression(&keyword.value, path, None));
+    }
+
+    for decorator in &value.decorator_list {
+        statement_value.merge_expression_value(handle_expression(decorator, path, None));
+    }
+
+    insert_unreachable_code(&mut statement_value, &value.body, path);
+
+    let mut bound_names = HashSet::new();
+    let mut read_names = HashSet::new();
 
     for statement in value.body {
-        statement_value.merge_statement_value(handle_statement(
+        let is_definition = matches!(
             statement,
-            &path,
-            Some(value.name.to_string()),
-        ));
+            ast::Stmt::FunctionDef(_) | ast::Stmt::AsyncFunctionDef(_) | ast::Stmt::ClassDef(_)
+        );
+
+        let body_value = handle_statement(statement, path, Some(class_name.clone()));
+
+        // Method bodies do not see the class scope, only the class body does.
+        if !is_definition {
+            for variable in &body_value.variables {
+                bound_names.insert(variable.name.clone());
+            }
 
-        statement_value.insert_class(value.name.to_string());
+            read_names.extend(body_value.expression_value.free_names.iter().cloned());
+        }
+
+        statement_value.merge_statement_value(body_value);
+    }
+
+    // `derived = base + 1` in a class body reads the class's own `base`.
+    for name in read_names.intersection(&bound_names) {
+        statement_value
+            .expression_value
+            .insert_name(format!("{}.{}", class_name, name));
     }
 
+    statement_value.set_unreachable_code_scope(&class_name);
+
     statement_value
 }
 
+fn is_type_checking_test(test: &ast::Expr) -> bool {
+    match test {
+        ast::Expr::Name(value) => value.id.as_str() == TYPE_CHECKING,
+        ast::Expr::Attribute(value) => value.attr.as_str() == TYPE_CHECKING,
+        _ => false,
+    }
+}
+
+fn mark_type_checking_imports(statement_value: &mut StatementValue) {
+    for file_data in statement_value.files.values_mut() {
+        for imports in file_data.imports.values_mut() {
+            for import_data in imports {
+                import_data.is_type_checking = true;
+            }
+        }
+    }
+}
+
+fn insert_exported_names(
+    statement_value: &mut StatementValue,
+    target: &ast::Expr,
+    value: &ast::Expr,
+    path: &Path,
+) {
+    let is_all_exports = match target {
+        ast::Expr::Name(name) => name.id.as_str() == ALL_EXPORTS,
+        _ => false,
+    };
+
+    let elements = match value {
+        ast::Expr::List(list) if is_all_exports => &list.elts,
+        ast::Expr::Tuple(tuple) if is_all_exports => &tuple.elts,
+        _ => return,
+    };
+
+    let mut file_data = FileData::new(path.to_path_buf());
+
+    for element in elements {
+        if let ast::Expr::Constant(constant) = element
+            && let ast::Constant::Str(name) = &constant.value
+        {
+            file_data.exported_names.insert(name.clone());
+        }
+    }
+
+    statement_value.merge_file_data(file_data);
+}
+
+fn collect_target_names(target: &ast::Expr, names: &mut Vec<(String, TextRange)>) {
+    match target {
+        ast::Expr::Name(value) => names.push((value.id.to_string(), value.range)),
+        ast::Expr::Tuple(value) => {
+            for element in &value.elts {
+                collect_target_names(element, names);
+            }
+        }
+        ast::Expr::List(value) => {
+            for element in &value.elts {
+                collect_target_names(element, names);
+            }
+        }
+        ast::Expr::Starred(value) => collect_target_names(&value.value, names),
+        _ => {}
+    }
+}
+
+fn insert_variables(
+    statement_value: &mut StatementValue,
+    target: &ast::Expr,
+    path: &Path,
+    class_name: &Option<String>,
+) {
+    let mut names = Vec::new();
+
+    collect_target_names(target, &mut names);
+
+    for (name, range) in names {
+        statement_value.insert_variable(VariableDef::new(
+            name,
+            path.to_path_buf(),
+            class_name.clone(),
+            range,
+        ));
+    }
+}
+
 fn get_empty_result() -> StatementValue {
     let statement_value = StatementValue::new();
 
@@ -82,8 +532,6 @@ fn handle_statement(
     path: &PathBuf,
     class_name: Option<String>,
 ) -> StatementValue {
-    dbg!(&statement);
-
     match statement {
         ast::Stmt::FunctionDef(value) => handle_function_def(
             FunctionDefOptions::StmtFunctionDef(value),
@@ -127,7 +575,6 @@ fn handle_statement(
 
             let right_side = handle_expression(&value.value, path, class_name.clone());
 
-            dbg!(&right_side.used_names);
             let right_side_values = right_side.used_names.clone();
 
             statement_value.merge_expression_value(right_side);
@@ -135,18 +582,28 @@ fn handle_statement(
             for target in &value.targets {
                 let left_side = handle_expression(target, path, class_name.clone());
 
-                left_side_values.extend(left_side.used_names.clone());
+                left_side_values.extend(left_side.assigned_names.clone());
 
-                dbg!(&left_side.used_names);
                 statement_value.merge_expression_value(left_side);
             }
 
-            if right_side_values.len() == 1 {
+            for target in &value.targets {
+                insert_variables(&mut statement_value, target, path, &class_name);
+                insert_exported_names(&mut statement_value, target, &value.value, path);
+            }
+
+            // `r = b.mod.Repo(path)` makes `r` an instance of `b.mod.Repo`.
+            let assigned_name = match value.value.as_ref() {
+                ast::Expr::Call(call) => get_attribute_chain(&call.func),
+                _ if right_side_values.len() == 1 => right_side_values.iter().next().cloned(),
+                _ => None,
+            };
+
+            if let Some(assigned_name) = assigned_name {
                 for left_side in left_side_values {
-                    statement_value.assignments.insert(
-                        left_side,
-                        right_side_values.iter().next().clone().unwrap().to_string(),
-                    );
+                    statement_value
+                        .assignments
+                        .insert(left_side, assigned_name.clone());
                 }
             }
 
@@ -155,6 +612,13 @@ fn handle_statement(
         ast::Stmt::AugAssign(value) => {
             let mut statement_value = StatementValue::new();
 
+            for operand in [&value.target, &value.value] {
+                statement_value.expression_value.insert_trigger(
+                    Trigger::AugmentedAssignment(value.op),
+                    get_receiver(operand, &class_name),
+                );
+            }
+
             statement_value.merge_expression_value(handle_expression(
                 &value.value,
                 path,
@@ -166,6 +630,15 @@ fn handle_statement(
                 class_name.clone(),
             ));
 
+            insert_exported_names(&mut statement_value, &value.target, &value.value, path);
+
+            // `x += 1` reads `x` before binding it again.
+            if let ast::Expr::Name(target) = value.target.as_ref() {
+                statement_value
+                    .expression_value
+                    .insert_free_name(target.id.to_string());
+            }
+
             statement_value
         }
         ast::Stmt::AnnAssign(value) => {
@@ -179,7 +652,7 @@ fn handle_statement(
                 ));
             }
 
-            statement_value.merge_expression_value(handle_expression(
+            statement_value.merge_expression_value(handle_annotation(
                 &value.annotation,
                 path,
                 class_name.clone(),
@@ -190,6 +663,11 @@ fn handle_statement(
                 class_name.clone(),
             ));
 
+            if let Some(assigned_value) = &value.value {
+                insert_variables(&mut statement_value, &value.target, path, &class_name);
+                insert_exported_names(&mut statement_value, &value.target, assigned_value, path);
+            }
+
             statement_value
         }
 
@@ -209,6 +687,10 @@ fn handle_statement(
         ast::Stmt::For(value) => {
             let mut statement_value = StatementValue::new();
 
+            statement_value
+                .expression_value
+                .insert_trigger(Trigger::Iteration, get_receiver(&value.iter, &class_name));
+
             statement_value.merge_expression_value(handle_expression(
                 &value.target,
                 path,
@@ -220,6 +702,8 @@ fn handle_statement(
                 class_name.clone(),
             ));
 
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
             for statement in value.body {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -228,6 +712,8 @@ fn handle_statement(
                 ));
             }
 
+            insert_unreachable_code(&mut statement_value, &value.orelse, path);
+
             for statement in value.orelse {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -241,6 +727,11 @@ fn handle_statement(
         ast::Stmt::AsyncFor(value) => {
             let mut statement_value = StatementValue::new();
 
+            statement_value.expression_value.insert_trigger(
+                Trigger::AsyncIteration,
+                get_receiver(&value.iter, &class_name),
+            );
+
             statement_value.merge_expression_value(handle_expression(
                 &value.target,
                 path,
@@ -252,6 +743,8 @@ fn handle_statement(
                 class_name.clone(),
             ));
 
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
             for statement in value.body {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -260,6 +753,8 @@ fn handle_statement(
                 ));
             }
 
+            insert_unreachable_code(&mut statement_value, &value.orelse, path);
+
             for statement in value.orelse {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -273,12 +768,34 @@ fn handle_statement(
         ast::Stmt::While(value) => {
             let mut statement_value = StatementValue::new();
 
+            statement_value
+                .expression_value
+                .insert_trigger(Trigger::Truth, get_receiver(&value.test, &class_name));
+
             statement_value.merge_expression_value(handle_expression(
                 &value.test,
                 path,
                 class_name.clone(),
             ));
 
+            match get_constant_test(&value.test) {
+                Some(true) => insert_unreachable_block(
+                    &mut statement_value,
+                    &value.orelse,
+                    path,
+                    "`else` of an infinite loop is never executed",
+                ),
+                Some(false) => insert_unreachable_block(
+                    &mut statement_value,
+                    &value.body,
+                    path,
+                    "body of a `while` that is always false is never executed",
+                ),
+                None => {}
+            }
+
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
             for statement in value.body {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -287,6 +804,8 @@ fn handle_statement(
                 ));
             }
 
+            insert_unreachable_code(&mut statement_value, &value.orelse, path);
+
             for statement in value.orelse {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -299,6 +818,11 @@ fn handle_statement(
         }
         ast::Stmt::If(value) => {
             let mut statement_value = StatementValue::new();
+            let is_type_checking = is_type_checking_test(&value.test);
+
+            statement_value
+                .expression_value
+                .insert_trigger(Trigger::Truth, get_receiver(&value.test, &class_name));
 
             statement_value.merge_expression_value(handle_expression(
                 &value.test,
@@ -306,14 +830,36 @@ fn handle_statement(
                 class_name.clone(),
             ));
 
-            for statement in value.body {
-                statement_value.merge_statement_value(handle_statement(
-                    statement,
+            match get_constant_test(&value.test) {
+                Some(true) => insert_unreachable_block(
+                    &mut statement_value,
+                    &value.orelse,
                     path,
-                    class_name.clone(),
-                ));
+                    "`else` of an `if` that is always true is never executed",
+                ),
+                Some(false) => insert_unreachable_block(
+                    &mut statement_value,
+                    &value.body,
+                    path,
+                    "body of an `if` that is always false is never executed",
+                ),
+                None => {}
+            }
+
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
+            for statement in value.body {
+                let mut body_value = handle_statement(statement, path, class_name.clone());
+
+                if is_type_checking {
+                    mark_type_checking_imports(&mut body_value);
+                }
+
+                statement_value.merge_statement_value(body_value);
             }
 
+            insert_unreachable_code(&mut statement_value, &value.orelse, path);
+
             for statement in value.orelse {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -327,6 +873,14 @@ fn handle_statement(
         ast::Stmt::With(value) => {
             let mut statement_value = StatementValue::new();
 
+            for item in &value.items {
+                statement_value
+                    .expression_value
+                    .insert_trigger(Trigger::With, get_receiver(&item.context_expr, &class_name));
+            }
+
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
             for statement in value.body {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -356,6 +910,15 @@ fn handle_statement(
         ast::Stmt::AsyncWith(value) => {
             let mut statement_value = StatementValue::new();
 
+            for item in &value.items {
+                statement_value.expression_value.insert_trigger(
+                    Trigger::AsyncWith,
+                    get_receiver(&item.context_expr, &class_name),
+                );
+            }
+
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
             for statement in value.body {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -400,6 +963,8 @@ fn handle_statement(
                     ));
                 }
 
+                insert_unreachable_code(&mut statement_value, &case.body, path);
+
                 for statement in case.body.clone() {
                     statement_value.merge_statement_value(handle_statement(
                         statement,
@@ -435,6 +1000,8 @@ fn handle_statement(
         ast::Stmt::Try(value) => {
             let mut statement_value = StatementValue::new();
 
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
             for statement in value.body {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -443,6 +1010,8 @@ fn handle_statement(
                 ));
             }
 
+            insert_unreachable_code(&mut statement_value, &value.orelse, path);
+
             for statement in value.orelse {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -451,6 +1020,8 @@ fn handle_statement(
                 ));
             }
 
+            insert_unreachable_code(&mut statement_value, &value.finalbody, path);
+
             for statement in value.finalbody {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -470,6 +1041,8 @@ fn handle_statement(
                             ));
                         }
 
+                        insert_unreachable_code(&mut statement_value, &except_handler.body, path);
+
                         for statement in except_handler.body {
                             statement_value.merge_statement_value(handle_statement(
                                 statement,
@@ -486,6 +1059,8 @@ fn handle_statement(
         ast::Stmt::TryStar(value) => {
             let mut statement_value = StatementValue::new();
 
+            insert_unreachable_code(&mut statement_value, &value.body, path);
+
             for statement in value.body {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -494,6 +1069,8 @@ fn handle_statement(
                 ));
             }
 
+            insert_unreachable_code(&mut statement_value, &value.orelse, path);
+
             for statement in value.orelse {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -502,6 +1079,8 @@ fn handle_statement(
                 ));
             }
 
+            insert_unreachable_code(&mut statement_value, &value.finalbody, path);
+
             for statement in value.finalbody {
                 statement_value.merge_statement_value(handle_statement(
                     statement,
@@ -521,6 +1100,8 @@ fn handle_statement(
                             ));
                         }
 
+                        insert_unreachable_code(&mut statement_value, &except_handler.body, path);
+
                         for statement in except_handler.body {
                             statement_value.merge_statement_value(handle_statement(
                                 statement,
@@ -537,6 +1118,10 @@ fn handle_statement(
         ast::Stmt::Assert(value) => {
             let mut statement_value = StatementValue::new();
 
+            statement_value
+                .expression_value
+                .insert_trigger(Trigger::Truth, get_receiver(&value.test, &class_name));
+
             if value.msg.is_some() {
                 statement_value.merge_expression_value(handle_expression(
                     &value.msg.clone().unwrap(),
@@ -566,54 +1151,61 @@ fn handle_statement(
                     Some(inner_name) => inner_name.to_string(),
                 };
 
-                let alias = name
-                    .asname
-                    .clone()
-                    .map_or(None, |inner| Some(inner.to_string()));
+                let alias = name.asname.as_ref().map(|inner| inner.to_string());
+
+                file_data.insert_import(Imports::new(path, relevant_name, alias, name.range));
+            }
+
+            statement_value.files.insert(path.to_path_buf(), file_data);
+
+            statement_value
+        }
+        ast::Stmt::ImportFrom(value) => {
+            let mut statement_value = StatementValue::new();
+
+            let mut file_data = FileData::new(path.to_path_buf());
+
+            let module = value
+                .module
+                .as_ref()
+                .map_or(String::new(), |inner| inner.to_string());
 
-                let import_data = Imports::new(path, relevant_name, alias);
+            let level = value.level.map_or(0, |inner| inner.to_u32());
 
-                file_data
-                    .imports
-                    .entry(name.name.to_string())
-                    .and_modify(|list| list.push(import_data.clone()))
-                    .or_insert(vec![import_data]);
+            for name in &value.names {
+                let alias = name.asname.as_ref().map(|inner| inner.to_string());
+
+                file_data.insert_import(Imports::new_from_import(
+                    module.clone(),
+                    name.name.to_string(),
+                    alias,
+                    level,
+                    name.range,
+                ));
             }
 
             statement_value.files.insert(path.to_path_buf(), file_data);
 
-            // [/home/saar/dev/rust/py-space/py-space-utils/src/parse_statement.rs:83:5] &statement = ImportFrom(
-            //     StmtImportFrom {
-            //         range: 13..56,
-            //         module: Some(
-            //             Identifier(
-            //                 "tests.unused_two",
-            //             ),
-            //         ),
-            //         names: [
-            //             Alias {
-            //                 range: 42..56,
-            //                 name: Identifier(
-            //                     "SAVE_FUNCTIONS",
-            //                 ),
-            //                 asname: None,
-            //             },
-            //         ],
-            //         level: Some(
-            //             Int(
-            //                 0,
-            //             ),
-            //         ),
-            //     },
-            // )
-
-            dbg!(value.names);
+            statement_value
+        }
+        ast::Stmt::Global(value) => {
+            let mut statement_value = StatementValue::new();
+
+            for name in &value.names {
+                statement_value.global_names.insert(name.to_string());
+            }
+
+            statement_value
+        }
+        ast::Stmt::Nonlocal(value) => {
+            let mut statement_value = StatementValue::new();
+
+            for name in &value.names {
+                statement_value.nonlocal_names.insert(name.to_string());
+            }
 
             statement_value
         }
-        ast::Stmt::ImportFrom(_value) => get_empty_result(),
-        ast::Stmt::Global(_value) => get_empty_result(),
-        ast::Stmt::Nonlocal(_value) => get_empty_result(),
         ast::Stmt::Pass(_value) => get_empty_result(),
         ast::Stmt::Break(_value) => get_empty_result(),
         ast::Stmt::Continue(_value) => get_empty_result(),
@@ -622,24 +1214,42 @@ fn handle_statement(
     }
 }
 
+fn collect_suppressions(content: &str, line_index: &LineIndex) -> Vec<(usize, Suppression)> {
+    let mut suppressions = Vec::new();
+
+    for token in lex(content, Mode::Module).flatten() {
+        if let (Tok::Comment(comment), range) = token
+            && let Some(suppression) = Suppression::from_comment(&comment)
+        {
+            suppressions.push((line_index.location(range.start()).line, suppression));
+        }
+    }
+
+    suppressions
+}
+
 pub fn extract_file_data(path: PathBuf) -> Result<StatementValue, PySpaceError> {
     let mut statement_value = StatementValue::new();
 
     let content = match fs::read_to_string(&path) {
         Ok(value) => value,
         Err(error) => {
-            dbg!("faild to read file: {}", &error);
-
-            return Err(PySpaceError::FailedToReadFile(error.to_string()));
+            return Err(PySpaceError::FailedToReadFile(format!(
+                "{}: {}",
+                path.display(),
+                error
+            )));
         }
     };
 
     let program = match parse(&content, Mode::Module, "<embedded>") {
         Ok(value) => value,
         Err(error) => {
-            dbg!("failed to parse file: {}", &error);
-
-            return Err(PySpaceError::FailedToParseFile(error.to_string()));
+            return Err(PySpaceError::FailedToParseFile(format!(
+                "{}: {}",
+                path.display(),
+                error
+            )));
         }
     };
 
@@ -652,9 +1262,25 @@ pub fn extract_file_data(path: PathBuf) -> Result<StatementValue, PySpaceError>
         }
     };
 
+    insert_unreachable_code(&mut statement_value, &body, &path);
+
     for statement in body {
         statement_value.merge_statement_value(handle_statement(statement, &path, None));
     }
 
+    let global_variables = std::mem::take(&mut statement_value.global_variables);
+    statement_value.variables.extend(global_variables);
+
+    let line_index = LineIndex::new(&content);
+    let mut file_data = FileData::new(path.clone());
+
+    for (line, suppression) in collect_suppressions(&content, &line_index) {
+        file_data.suppressions.insert(line, suppression);
+    }
+
+    statement_value.merge_file_data(file_data);
+
+    statement_value.resolve_locations(&line_index);
+
     Ok(statement_value)
 }
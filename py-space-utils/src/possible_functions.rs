@@ -1,5 +1,22 @@
+use log::trace;
+
 use crate::models::statement_value::StatementValue;
 
-pub fn handle_possible_functions(statement_value: StatementValue) -> StatementValue {
+/// Counts functions referenced without being called as used: stored in a list,
+/// passed as a callback to `map`, `sorted(key=...)` or
+/// `threading.Thread(target=...)`. Attribute references like `Dar.save` are
+/// matched by `fix_attributes`.
+pub fn handle_possible_functions(mut statement_value: StatementValue) -> StatementValue {
+    let functions_name = statement_value.build_qualified_name_functions();
+    let expression_value = &mut statement_value.expression_value;
+
+    for possible_function in &expression_value.possible_functions {
+        let name = possible_function.qualified_name();
+
+        if functions_name.contains(&name) && expression_value.used_functions.insert(name.clone()) {
+            trace!("{} is used as a value", name);
+        }
+    }
+
     statement_value
 }
@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    class_hierarchy::ClassHierarchy,
+    data_model::{DataModel, is_dunder},
+    models::{function_def::FunctionDef, statement_value::StatementValue},
+};
+
+const TEST_FUNCTION_PREFIX: &str = "test";
+const TEST_CLASS_PREFIX: &str = "Test";
+const TEST_PACKAGES: [&str; 2] = ["test", "tests"];
+const CONFTEST_MODULE: &str = "conftest";
+
+/// Setup and teardown functions test runners call by name.
+const TEST_HOOKS: [&str; 12] = [
+    "setUp",
+    "tearDown",
+    "setUpClass",
+    "tearDownClass",
+    "setup_module",
+    "teardown_module",
+    "setup_function",
+    "teardown_function",
+    "setup_class",
+    "teardown_class",
+    "setup_method",
+    "teardown_method",
+];
+
+/// Decorators that wrap a function without registering it anywhere. Any
+/// other decorator is taken to hand the function to a framework.
+const PLAIN_DECORATORS: [&str; 17] = [
+    "staticmethod",
+    "classmethod",
+    "property",
+    "abstractmethod",
+    "abc.abstractmethod",
+    "cached_property",
+    "functools.cached_property",
+    "cache",
+    "functools.cache",
+    "lru_cache",
+    "functools.lru_cache",
+    "wraps",
+    "functools.wraps",
+    "overload",
+    "typing.overload",
+    "override",
+    "typing.override",
+];
+
+fn is_test_module(module: &str) -> bool {
+    let mut parts = module.split(".").peekable();
+
+    while let Some(part) = parts.next() {
+        if parts.peek().is_some() {
+            if TEST_PACKAGES.contains(&part) {
+                return true;
+            }
+
+            continue;
+        }
+
+        return part.starts_with("test_") || part.ends_with("_test") || part == CONFTEST_MODULE;
+    }
+
+    false
+}
+
+fn is_test_function(function: &FunctionDef) -> bool {
+    if !is_test_module(&function.module) {
+        return false;
+    }
+
+    if TEST_HOOKS.contains(&function.name.as_str()) {
+        return true;
+    }
+
+    let is_test_class = function
+        .class_name
+        .as_ref()
+        .is_none_or(|class_name| class_name.starts_with(TEST_CLASS_PREFIX));
+
+    is_test_class && function.name.starts_with(TEST_FUNCTION_PREFIX)
+}
+
+fn is_framework_handler(function: &FunctionDef) -> bool {
+    function
+        .decorators
+        .iter()
+        .any(|decorator| !PLAIN_DECORATORS.contains(&decorator.as_str()))
+}
+
+/// Whether something outside the analyzed code calls the function: a test
+/// runner, or a framework it was registered with through a decorator.
+pub fn is_root(function: &FunctionDef) -> bool {
+    is_test_function(function) || is_framework_handler(function)
+}
+
+/// What the call graph reaches from a set of roots. Module-level code runs on
+/// import, `if __name__ == "__main__"` blocks included, so every module is a
+/// root too.
+pub struct Reachability {
+    reachable: HashSet<String>,
+    callers: HashMap<String, HashSet<String>>,
+}
+
+impl Reachability {
+    pub fn new(statement_value: &StatementValue, roots: Vec<String>) -> Self {
+        let call_graph = &statement_value.call_graph;
+        let hierarchy = ClassHierarchy::new(statement_value);
+        let data_model = DataModel::new(statement_value);
+
+        // Using a class runs the dunder methods the project has syntax for,
+        // `__eq__` when its instances compare with `==`, and `__init__` in
+        // any case.
+        let mut dunder_methods: HashMap<String, Vec<&FunctionDef>> = HashMap::new();
+
+        for function in &statement_value.functions {
+            if let Some(class_name) = &function.class_name
+                && is_dunder(&function.name)
+            {
+                let class_name = match function.module.is_empty() {
+                    true => class_name.clone(),
+                    false => format!("{}.{}", function.module, class_name),
+                };
+
+                dunder_methods.entry(class_name).or_default().push(function);
+            }
+        }
+
+        let mut reachable = HashSet::new();
+        let mut pending: VecDeque<String> = call_graph.modules.iter().cloned().collect();
+
+        pending.extend(roots);
+
+        while let Some(name) = pending.pop_front() {
+            if !reachable.insert(name.clone()) {
+                continue;
+            }
+
+            pending.extend(call_graph.callees(&name).cloned());
+            pending.extend(hierarchy.dispatch_targets(&name));
+
+            // A class runs the dunder methods it inherits too.
+            let mro = hierarchy.mro(&name);
+
+            for class_name in &mro {
+                for method in dunder_methods.get(class_name).into_iter().flatten() {
+                    if data_model.is_called(&mro, &method.name) {
+                        pending.push_back(method.qualified_name());
+                    }
+                }
+            }
+        }
+
+        let mut callers: HashMap<String, HashSet<String>> = HashMap::new();
+
+        for (class_name, methods) in &dunder_methods {
+            let mro = hierarchy.mro(class_name);
+
+            for method in methods {
+                if data_model.is_called(&mro, &method.name) {
+                    callers
+                        .entry(method.qualified_name())
+                        .or_default()
+                        .insert(class_name.clone());
+                }
+            }
+        }
+
+        for (caller, callees) in &call_graph.edges {
+            for callee in callees {
+                for target in hierarchy.dispatch_targets(callee) {
+                    callers.entry(target).or_default().insert(caller.clone());
+                }
+
+                callers
+                    .entry(callee.clone())
+                    .or_default()
+                    .insert(caller.clone());
+            }
+        }
+
+        Self { reachable, callers }
+    }
+
+    pub fn is_reachable(&self, name: &str) -> bool {
+        self.reachable.contains(name)
+    }
+
+    /// The unreachable code `name` is used from, followed back to where it
+    /// starts: callers that nothing uses at all. Empty when `name` is only
+    /// used from a cycle.
+    pub fn dead_starts(&self, name: &str) -> Vec<String> {
+        let mut starts = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![name.to_string()];
+
+        while let Some(current) = pending.pop() {
+            for caller in self.callers.get(&current).into_iter().flatten() {
+                if self.is_reachable(caller) || !visited.insert(caller.clone()) {
+                    continue;
+                }
+
+                if !self.callers.contains_key(caller) {
+                    starts.push(caller.clone());
+                }
+
+                pending.push(caller.clone());
+            }
+        }
+
+        starts.sort();
+
+        starts
+    }
+}
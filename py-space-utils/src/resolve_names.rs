@@ -0,0 +1,291 @@
+use std::collections::{HashMap, HashSet};
+
+use log::trace;
+
+use crate::{
+    data_model::is_builtin,
+    models::{
+        function_def::FunctionDef, imports::Imports, statement_value::StatementValue,
+        trigger::Receiver,
+    },
+};
+
+fn get_package_name(module_name: &str, is_package: bool) -> String {
+    if is_package {
+        return module_name.to_string();
+    }
+
+    match module_name.rsplit_once(".") {
+        Some((package_name, _)) => package_name.to_string(),
+        None => String::new(),
+    }
+}
+
+fn resolve_import_module(import_data: &Imports, package_name: &str) -> String {
+    if import_data.level == 0 {
+        return import_data.path.clone();
+    }
+
+    let mut parts: Vec<&str> = package_name
+        .split(".")
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    for _ in 1..import_data.level {
+        parts.pop();
+    }
+
+    if !import_data.path.is_empty() {
+        parts.push(&import_data.path);
+    }
+
+    parts.join(".")
+}
+
+fn resolve_import_target(import_data: &Imports, package_name: &str) -> String {
+    let module = resolve_import_module(import_data, package_name);
+
+    if import_data.is_from_import {
+        if module.is_empty() {
+            return import_data.name.clone();
+        }
+
+        return format!("{}.{}", module, import_data.name);
+    }
+
+    if import_data.alias.is_some() {
+        return module;
+    }
+
+    import_data.bound_name()
+}
+
+fn build_bound_names(
+    statement_value: &StatementValue,
+    module_name: &str,
+    package_name: &str,
+) -> HashMap<String, String> {
+    let mut bound_names = HashMap::new();
+
+    let qualify = |name: &str| {
+        if module_name.is_empty() {
+            return name.to_string();
+        }
+
+        format!("{}.{}", module_name, name)
+    };
+
+    for function in &statement_value.functions {
+        if function.class_name.is_none() {
+            bound_names.insert(function.name.clone(), qualify(&function.name));
+        }
+    }
+
+    let mut variable_names = HashSet::new();
+
+    for variable in &statement_value.variables {
+        if variable.class_name.is_none() {
+            bound_names.insert(variable.name.clone(), qualify(&variable.name));
+            variable_names.insert(variable.name.clone());
+        }
+    }
+
+    for class in &statement_value.classes {
+        bound_names.insert(class.name.clone(), qualify(&class.name));
+    }
+
+    for file_data in statement_value.files.values() {
+        let mut import_paths: Vec<&String> = file_data.imports.keys().collect();
+        import_paths.sort();
+
+        for import_path in import_paths {
+            for import_data in &file_data.imports[import_path] {
+                // `try: import ssl` with `except ImportError: ssl = None`
+                // binds the name both ways, and the variable keeps it so
+                // that reads of `ssl` count for it.
+                if variable_names.contains(&import_data.bound_name()) {
+                    continue;
+                }
+
+                bound_names.insert(
+                    import_data.bound_name(),
+                    resolve_import_target(import_data, package_name),
+                );
+            }
+        }
+    }
+
+    bound_names
+}
+
+fn resolve_name(name: &str, bound_names: &HashMap<String, String>) -> Option<String> {
+    let (first_part, rest) = match name.split_once(".") {
+        Some((first_part, rest)) => (first_part, Some(rest)),
+        None => (name, None),
+    };
+
+    let target = bound_names.get(first_part)?;
+
+    match rest {
+        Some(value) => Some(format!("{}.{}", target, value)),
+        None => Some(target.clone()),
+    }
+}
+
+fn resolve_name_set(
+    names: &HashSet<String>,
+    bound_names: &HashMap<String, String>,
+) -> HashSet<String> {
+    let mut resolved_names = HashSet::new();
+
+    for name in names {
+        match resolve_name(name, bound_names) {
+            Some(resolved_name) => {
+                trace!("resolved {} to {}", name, resolved_name);
+
+                resolved_names.insert(resolved_name);
+            }
+            None => {
+                resolved_names.insert(name.clone());
+            }
+        }
+    }
+
+    resolved_names
+}
+
+/// Qualifies the name a receiver is given by. Names the module does not bind
+/// are parameters or locals that may hold anything, builtins aside.
+fn resolve_receiver(receiver: Receiver, bound_names: &HashMap<String, String>) -> Receiver {
+    let (name, is_instance) = match &receiver {
+        Receiver::Name(name) => (name, false),
+        Receiver::Instance(name) => (name, true),
+        Receiver::Literal | Receiver::Unknown => return receiver,
+    };
+
+    let first_part = name.split(".").next().unwrap_or(name);
+
+    let name = match resolve_name(name, bound_names) {
+        Some(resolved_name) => resolved_name,
+        None if is_builtin(first_part) => name.clone(),
+        None => return Receiver::Unknown,
+    };
+
+    match is_instance {
+        true => Receiver::Instance(name),
+        false => Receiver::Name(name),
+    }
+}
+
+/// Points each name that may be a function used as a value at the definition
+/// it names, `module.function` or, in a class body, `module.Class.method`.
+fn resolve_possible_functions(
+    possible_functions: HashSet<FunctionDef>,
+    bound_names: &HashMap<String, String>,
+    module_name: &str,
+) -> HashSet<FunctionDef> {
+    let mut resolved_functions = HashSet::new();
+
+    for mut function in possible_functions {
+        match resolve_name(&function.name, bound_names) {
+            Some(resolved_name) => {
+                let (module, name) = match resolved_name.rsplit_once(".") {
+                    Some((module, name)) => (module.to_string(), name.to_string()),
+                    None => (String::new(), resolved_name),
+                };
+
+                function.module = module;
+                function.name = name;
+                function.class_name = None;
+            }
+            None => function.module = module_name.to_string(),
+        }
+
+        resolved_functions.insert(function);
+    }
+
+    resolved_functions
+}
+
+pub fn resolve_names(
+    mut statement_value: StatementValue,
+    module_name: &str,
+    is_package: bool,
+) -> StatementValue {
+    let package_name = get_package_name(module_name, is_package);
+    let bound_names = build_bound_names(&statement_value, module_name, &package_name);
+
+    statement_value.set_module(module_name);
+
+    let classes = std::mem::take(&mut statement_value.classes);
+
+    statement_value.classes = classes
+        .into_iter()
+        .map(|mut class| {
+            class.bases = class
+                .bases
+                .iter()
+                .map(|base| resolve_name(base, &bound_names).unwrap_or_else(|| base.clone()))
+                .collect();
+
+            class
+        })
+        .collect();
+
+    let expression_value = &mut statement_value.expression_value;
+
+    expression_value.used_functions =
+        resolve_name_set(&expression_value.used_functions, &bound_names);
+
+    expression_value.used_attributes =
+        resolve_name_set(&expression_value.used_attributes, &bound_names);
+
+    expression_value.used_names = resolve_name_set(&expression_value.used_names, &bound_names);
+
+    expression_value.triggers = std::mem::take(&mut expression_value.triggers)
+        .into_iter()
+        .map(|(trigger, receiver)| (trigger, resolve_receiver(receiver, &bound_names)))
+        .collect();
+
+    expression_value.possible_functions = resolve_possible_functions(
+        std::mem::take(&mut expression_value.possible_functions),
+        &bound_names,
+        module_name,
+    );
+
+    // Names read in a class body that nothing in the module binds are the
+    // class's own members, `get` in `value = property(get)`.
+    let mut class_members: HashMap<&str, Vec<String>> = HashMap::new();
+
+    for function in &expression_value.possible_functions {
+        if function.class_name.is_some() && !bound_names.contains_key(&function.name) {
+            class_members
+                .entry(&function.name)
+                .or_default()
+                .push(function.qualified_name());
+        }
+    }
+
+    let call_graph = &mut statement_value.call_graph;
+
+    for names in call_graph
+        .edges
+        .values_mut()
+        .chain(call_graph.calls.values_mut())
+    {
+        *names = resolve_name_set(names, &bound_names);
+    }
+
+    for names in call_graph.edges.values_mut() {
+        let members: Vec<String> = names
+            .iter()
+            .filter_map(|name| class_members.get(name.as_str()))
+            .flatten()
+            .cloned()
+            .collect();
+
+        names.extend(members);
+    }
+
+    statement_value
+}
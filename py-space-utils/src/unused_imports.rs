@@ -0,0 +1,45 @@
+use log::trace;
+
+use crate::models::{expression_value::ExpressionValue, statement_value::StatementValue};
+
+const FUTURE_MODULE: &str = "__future__";
+const STAR_IMPORT: &str = "*";
+
+fn is_name_referenced(bound_name: &str, expression_value: &ExpressionValue) -> bool {
+    let prefix = format!("{}.", bound_name);
+
+    expression_value
+        .used_names
+        .iter()
+        .chain(&expression_value.used_functions)
+        .chain(&expression_value.used_attributes)
+        .any(|name| name == bound_name || name.starts_with(&prefix))
+}
+
+/// Flags the imports of a single, not yet merged, file whose bound name is
+/// referenced in that file. Imports in `__init__.py` are treated as
+/// re-exports and always count as used.
+pub fn mark_used_imports(mut statement_value: StatementValue, is_package: bool) -> StatementValue {
+    let expression_value = &statement_value.expression_value;
+
+    for file_data in statement_value.files.values_mut() {
+        for imports in file_data.imports.values_mut() {
+            for import_data in imports {
+                let bound_name = import_data.bound_name();
+
+                import_data.is_used = is_package
+                    || import_data.path == FUTURE_MODULE
+                    || import_data.name == STAR_IMPORT
+                    || import_data.is_explicit_reexport()
+                    || file_data.exported_names.contains(&bound_name)
+                    || is_name_referenced(&bound_name, expression_value);
+
+                if !import_data.is_used {
+                    trace!("import {} is never referenced", bound_name);
+                }
+            }
+        }
+    }
+
+    statement_value
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn takes_the_attribute_receiver_from_the_expression() {
+    let project = Project::new("attribute-receiver")
+        .file(
+            "models.py",
+            r#"
+            class Thing:
+                def __init__(self, value):
+                    self.value = value
+
+                def save(self):
+                    return self.value
+            "#,
+        )
+        .file(
+            "app.py",
+            r#"
+            import models
+
+            config = 1
+            handler = models.Thing(config).save
+            handler()
+            "#,
+        );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn keeps_the_whole_chain_of_a_dotted_attribute() {
+    let project = Project::new("attribute-chain")
+        .file("pkg/__init__.py", "")
+        .file(
+            "pkg/tools.py",
+            r#"
+            def run():
+                return 1
+
+
+            def stop():
+                return 2
+            "#,
+        )
+        .file(
+            "app.py",
+            r#"
+            import pkg.tools
+
+            callbacks = [pkg.tools.run]
+            print(callbacks)
+            "#,
+        );
+
+    assert_eq!(project.findings(), ["unused-function pkg.tools.stop"]);
+}
@@ -0,0 +1,98 @@
+// Each test crate uses its own subset of these helpers.
+#![allow(dead_code)]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+use py_space_utils::{
+    analyzer::analyze_project,
+    models::{config::Config, report::Report},
+};
+
+/// Python sources written to a fresh directory, removed again on drop.
+pub struct Project {
+    root: PathBuf,
+}
+
+impl Project {
+    pub fn new(name: &str) -> Self {
+        let root = env::temp_dir().join(format!("py-space-{}-{}", name, process::id()));
+
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+
+        Self { root }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Writes `source` to `path`, stripping the indentation its lines share
+    /// so tests can indent Python along with the Rust around it.
+    pub fn file(self, path: &str, source: &str) -> Self {
+        let path = self.root.join(path);
+
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, dedent(source)).unwrap();
+
+        self
+    }
+
+    pub fn config(&self) -> Config {
+        let mut config = Config::new();
+
+        config.paths = vec![self.root.clone()];
+        config.project_root = Some(self.root.clone());
+
+        config
+    }
+
+    pub fn check(&self) -> Report {
+        self.check_with(&self.config())
+    }
+
+    pub fn check_with(&self, config: &Config) -> Report {
+        analyze_project(config).unwrap()
+    }
+
+    /// Every finding as `kind name`, `unused-function app.save`.
+    pub fn findings(&self) -> Vec<String> {
+        self.findings_with(&self.config())
+    }
+
+    pub fn findings_with(&self, config: &Config) -> Vec<String> {
+        self.check_with(config)
+            .findings
+            .iter()
+            .map(|finding| format!("{} {}", finding.kind, finding.qualified_name))
+            .collect()
+    }
+}
+
+impl Drop for Project {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn dedent(source: &str) -> String {
+    let source = source.trim_start_matches('\n');
+
+    let indent = source
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    source
+        .lines()
+        .map(|line| line.get(indent..).unwrap_or(""))
+        .collect::<Vec<&str>>()
+        .join("\n")
+        + "\n"
+}
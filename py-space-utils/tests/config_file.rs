@@ -0,0 +1,89 @@
+mod common;
+
+use common::Project;
+use py_space_utils::{
+    config_file::load_config,
+    errors::py_space::PySpaceError,
+    models::{config::OutputFormat, finding_kind::FindingKind},
+};
+
+#[test]
+fn prefers_the_pyproject_table_over_a_standalone_file() {
+    let project = Project::new("config-pyproject")
+        .file(
+            "pyproject.toml",
+            r#"
+            [tool.py-space]
+            min-confidence = 60
+            "#,
+        )
+        .file(
+            "py-space.toml",
+            r#"
+            min-confidence = 80
+            "#,
+        );
+
+    let config = load_config(None, Some(project.root())).unwrap();
+
+    assert_eq!(config.min_confidence, 60);
+}
+
+#[test]
+fn falls_back_to_the_standalone_file_without_a_pyproject_table() {
+    let project = Project::new("config-standalone")
+        .file(
+            "pyproject.toml",
+            r#"
+            [project]
+            name = "app"
+            "#,
+        )
+        .file(
+            "py-space.toml",
+            r#"
+            roots = ["src"]
+            enable = ["unused-function"]
+            format = "sarif"
+            "#,
+        )
+        .file("src/app/__init__.py", "");
+
+    let config = load_config(None, Some(&project.root().join("src/app"))).unwrap();
+
+    assert_eq!(config.paths, [project.root().join("src")]);
+    assert_eq!(config.project_root.as_deref(), Some(project.root()));
+    assert_eq!(config.enabled_kinds, [FindingKind::UnusedFunction]);
+    assert_eq!(config.output_format, OutputFormat::Sarif);
+}
+
+#[test]
+fn rejects_unknown_keys() {
+    let project = Project::new("config-unknown-key")
+        .file(
+            "pyproject.toml",
+            r#"
+            [tool.py-space]
+            min-confidense = 60
+            "#,
+        )
+        .file(
+            "py-space.toml",
+            r#"
+            [unused-arguments]
+            ignore-self = true
+            "#,
+        );
+
+    let error = load_config(None, Some(project.root())).unwrap_err();
+
+    assert!(
+        matches!(&error, PySpaceError::InvalidConfig(message) if message.contains("min-confidense"))
+    );
+
+    let error = load_config(Some(&project.root().join("py-space.toml")), None).unwrap_err();
+
+    assert!(
+        matches!(&error, PySpaceError::InvalidConfig(message) if message.contains("ignore-self"))
+    );
+}
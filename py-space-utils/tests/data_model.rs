@@ -0,0 +1,169 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn reports_call_and_getattr_of_instances_never_called_or_looked_into() {
+    let project = Project::new("unused-call").file(
+        "app.py",
+        r#"
+        import os
+
+
+        class Greeter:
+            def __call__(self):
+                return "hi"
+
+            def greet(self):
+                return os.getcwd()
+
+
+        class Settings:
+            def __getattr__(self, name):
+                return name
+
+
+        def main():
+            greeter = Greeter()
+            print(greeter.greet(), Settings())
+
+            return main
+
+
+        main()
+        "#,
+    );
+
+    assert_eq!(
+        project.findings(),
+        [
+            "unused-method app.Greeter.__call__",
+            "unused-method app.Settings.__getattr__"
+        ]
+    );
+}
+
+#[test]
+fn keeps_call_and_getattr_of_instances_called_or_looked_into() {
+    let project = Project::new("used-call").file(
+        "app.py",
+        r#"
+        class Greeter:
+            def __call__(self):
+                return "hi"
+
+
+        class Settings:
+            def __getattr__(self, name):
+                return name
+
+
+        def main():
+            greeter = Greeter()
+            settings = Settings()
+
+            return greeter(), settings.debug
+
+
+        main()
+        "#,
+    );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn keeps_dunder_methods_of_objects_the_names_do_not_tell() {
+    let project = Project::new("unknown-receiver").file(
+        "app.py",
+        r#"
+        class Handler:
+            def __call__(self):
+                return 1
+
+
+        def run(callback):
+            return callback()
+
+
+        run(Handler())
+        "#,
+    );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn ties_comparisons_and_iteration_to_their_operands() {
+    let project = Project::new("operands").file(
+        "app.py",
+        r#"
+        class Money:
+            def __eq__(self, other):
+                return True
+
+
+        class Point:
+            def __eq__(self, other):
+                return True
+
+
+        class Basket:
+            def __iter__(self):
+                return self
+
+
+        class Crate:
+            def __iter__(self):
+                return self
+
+
+        def main():
+            price = Money()
+            origin = Point()
+
+            for item in Basket():
+                print(item, origin, Crate())
+
+            return price == 1
+
+
+        main()
+        "#,
+    );
+
+    assert_eq!(
+        project.findings(),
+        [
+            "unused-method app.Point.__eq__",
+            "unused-method app.Crate.__iter__"
+        ]
+    );
+}
+
+#[test]
+fn keeps_dunder_methods_a_subclass_instance_inherits() {
+    let project = Project::new("inherited-call").file(
+        "app.py",
+        r#"
+        class Base:
+            def __call__(self):
+                return 1
+
+
+        class Child(Base):
+            pass
+
+
+        def main():
+            child = Child()
+
+            return child()
+
+
+        main()
+        "#,
+    );
+
+    assert!(project.findings().is_empty());
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn reports_code_called_only_from_a_dead_function() {
+    let project = Project::new("dead-caller").file(
+        "app.py",
+        r#"
+        def helper():
+            return 1
+
+
+        def caller():
+            return helper()
+        "#,
+    );
+
+    let report = project.check();
+    let findings: Vec<String> = report
+        .findings
+        .iter()
+        .map(|finding| {
+            format!(
+                "{} {} {}",
+                finding.kind, finding.qualified_name, finding.reason
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        findings,
+        [
+            "dead-code app.helper only used from code that never runs, starting at app.caller",
+            "unused-function app.caller function is never called",
+        ]
+    );
+}
+
+#[test]
+fn keeps_code_run_from_a_main_block_alive() {
+    let project = Project::new("main-block").file(
+        "app.py",
+        r#"
+        def helper():
+            return 1
+
+
+        def main():
+            return helper()
+
+
+        if __name__ == "__main__":
+            main()
+        "#,
+    );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn keeps_tests_and_framework_handlers_as_roots() {
+    let project = Project::new("roots")
+        .file(
+            "app.py",
+            r#"
+            import functools
+
+            from framework import route
+
+
+            def render():
+                return 1
+
+
+            @route("/")
+            def index():
+                return render()
+
+
+            @functools.cache
+            def cached():
+                return 2
+            "#,
+        )
+        .file(
+            "tests/test_app.py",
+            r#"
+            def build():
+                return 3
+
+
+            def test_build():
+                assert build() == 3
+            "#,
+        );
+
+    assert_eq!(project.findings(), ["unused-function app.cached"]);
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use std::path::Path;
+
+use common::Project;
+
+#[test]
+fn skips_build_output_only_at_the_project_root() {
+    let project = Project::new("build-dirs")
+        .file(
+            "build/lib/app.py",
+            r#"
+            def copied():
+                return 1
+            "#,
+        )
+        .file(
+            "myapp/build/steps.py",
+            r#"
+            def compile_step():
+                return 1
+            "#,
+        );
+
+    assert_eq!(
+        project.findings(),
+        ["unused-function myapp.build.steps.compile_step"]
+    );
+}
+
+#[test]
+fn analyzes_overlapping_roots_once() {
+    let project = Project::new("overlapping-roots")
+        .file(
+            "app/models.py",
+            r#"
+            def save():
+                return 1
+            "#,
+        )
+        .file(
+            "scripts/run.py",
+            r#"
+            def run():
+                return 1
+            "#,
+        );
+
+    let mut config = project.config();
+    config.paths = vec![
+        project.root().join("app/models.py"),
+        project.root().to_path_buf(),
+        project.root().join("app"),
+    ];
+
+    assert_eq!(project.check_with(&config).files_scanned, 2);
+    assert_eq!(
+        project.findings_with(&config),
+        [
+            "unused-function app.models.save",
+            "unused-function scripts.run.run"
+        ]
+    );
+}
+
+#[test]
+fn reports_paths_relative_to_the_project_root() {
+    let project = Project::new("relative-paths").file(
+        "services/api/app/models.py",
+        r#"
+        def save():
+            return 1
+        "#,
+    );
+
+    let mut config = project.config();
+    config.paths = vec![project.root().join("services/api")];
+
+    let report = project.check_with(&config);
+    let files: Vec<&Path> = report
+        .findings
+        .iter()
+        .map(|finding| finding.file.as_path())
+        .collect();
+
+    assert_eq!(report.files_scanned, 1);
+    assert_eq!(files, [Path::new("services/api/app/models.py")]);
+
+    config.project_root = Some(project.root().join("services/api"));
+
+    let report = project.check_with(&config);
+
+    assert_eq!(report.findings[0].file, Path::new("app/models.py"));
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn resolves_from_imports_through_the_defining_module() {
+    let project = Project::new("from-import")
+        .file(
+            "pkg/models.py",
+            r#"
+            def save():
+                return 1
+
+
+            def load():
+                return 2
+            "#,
+        )
+        .file(
+            "pkg/views.py",
+            r#"
+            from pkg.models import save
+
+
+            def load():
+                return 3
+
+
+            save()
+            load()
+            "#,
+        );
+
+    assert_eq!(project.findings(), ["unused-function pkg.models.load"]);
+}
+
+#[test]
+fn resolves_relative_and_aliased_imports() {
+    let project = Project::new("relative-import")
+        .file("pkg/__init__.py", "")
+        .file(
+            "pkg/models.py",
+            r#"
+            def save():
+                return 1
+
+
+            def load():
+                return 2
+            "#,
+        )
+        .file(
+            "pkg/views.py",
+            r#"
+            from .models import save as store
+            from . import models
+
+            store()
+            models.load()
+            "#,
+        );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn resolves_calls_through_a_submodule_chain() {
+    let project = Project::new("submodule-call")
+        .file("pkg/__init__.py", "")
+        .file("pkg/tools/__init__.py", "")
+        .file(
+            "pkg/tools/helpers.py",
+            r#"
+            def run():
+                return 1
+
+
+            class Repo:
+                def load(self):
+                    return 2
+
+                def save(self):
+                    return 3
+            "#,
+        )
+        .file(
+            "app.py",
+            r#"
+            import pkg.tools.helpers
+
+            pkg.tools.helpers.run()
+
+            repo = pkg.tools.helpers.Repo("path")
+            repo.load()
+            "#,
+        );
+
+    assert_eq!(
+        project.findings(),
+        ["unused-method pkg.tools.helpers.Repo.save"]
+    );
+}
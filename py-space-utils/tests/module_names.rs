@@ -0,0 +1,76 @@
+mod common;
+
+use common::Project;
+use py_space_utils::config_file::load_config;
+
+#[test]
+fn counts_module_names_from_the_analyzed_root() {
+    let project = Project::new("analyzed-root")
+        .file(
+            "p5/pkg/tools.py",
+            r#"
+            def run():
+                return 1
+
+
+            def stop():
+                return 2
+            "#,
+        )
+        .file(
+            "p5/main.py",
+            r#"
+            from pkg.tools import run
+
+            run()
+            "#,
+        );
+
+    let mut config = project.config();
+    config.paths = vec![project.root().join("p5")];
+
+    assert_eq!(
+        project.findings_with(&config),
+        ["unused-function pkg.tools.stop"]
+    );
+
+    config.project_root = None;
+
+    assert_eq!(
+        project.findings_with(&config),
+        ["unused-function pkg.tools.stop"]
+    );
+}
+
+#[test]
+fn counts_module_names_from_a_configured_source_root() {
+    let project = Project::new("source-root")
+        .file(
+            "pyproject.toml",
+            r#"
+            [tool.py-space]
+            roots = ["src"]
+            "#,
+        )
+        .file(
+            "src/m.py",
+            r#"
+            def helper():
+                return 1
+            "#,
+        )
+        .file(
+            "src/pkg/__init__.py",
+            r#"
+            def setup():
+                return 1
+            "#,
+        );
+
+    let config = load_config(None, Some(project.root())).unwrap();
+
+    assert_eq!(
+        project.findings_with(&config),
+        ["unused-function m.helper", "unused-function pkg.setup"]
+    );
+}
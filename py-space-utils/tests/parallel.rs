@@ -0,0 +1,71 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn reports_the_same_findings_on_any_number_of_threads() {
+    let mut project = Project::new("parallel");
+
+    for index in 0..24 {
+        project = project.file(
+            &format!("pkg/module_{}.py", index),
+            &format!(
+                r#"
+                import os
+
+                from pkg.module_{next} import shared
+
+
+                class Handler:
+                    def handle(self, event):
+                        return shared()
+
+                    def close(self):
+                        return os.getcwd()
+
+
+                def shared():
+                    return 1
+
+
+                def helper_{index}():
+                    return Handler().handle(None)
+                "#,
+                index = index,
+                next = (index + 1) % 24,
+            ),
+        );
+    }
+
+    let render = |jobs| {
+        let mut config = project.config();
+        config.jobs = Some(jobs);
+
+        let report = project.check_with(&config);
+        let findings: Vec<String> = report
+            .findings
+            .iter()
+            .map(|finding| {
+                format!(
+                    "{}:{}:{} {} {}",
+                    finding.file.display(),
+                    finding.line,
+                    finding.column,
+                    finding.kind,
+                    finding.qualified_name
+                )
+            })
+            .collect();
+
+        (report.files_scanned, findings)
+    };
+
+    let (files_scanned, findings) = render(1);
+
+    assert_eq!(files_scanned, 24);
+    assert!(!findings.is_empty());
+
+    for _ in 0..3 {
+        assert_eq!(render(8), (files_scanned, findings.clone()));
+    }
+}
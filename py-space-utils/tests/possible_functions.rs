@@ -0,0 +1,83 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn counts_functions_stored_in_a_list_as_used() {
+    let project = Project::new("function-list").file(
+        "app.py",
+        r#"
+        class Dar:
+            @staticmethod
+            def save():
+                pass
+
+            @staticmethod
+            def load():
+                pass
+
+
+        def save():
+            pass
+
+
+        SAVE_FUNCTIONS = [save, Dar.save]
+
+        for function in SAVE_FUNCTIONS:
+            function()
+        "#,
+    );
+
+    assert_eq!(project.findings(), ["unused-method app.Dar.load"]);
+}
+
+#[test]
+fn counts_functions_passed_as_callbacks_as_used() {
+    let project = Project::new("function-callbacks").file(
+        "app.py",
+        r#"
+        import threading
+
+
+        def by_length(value):
+            return len(value)
+
+
+        def work():
+            return 1
+
+
+        def idle():
+            return 2
+
+
+        print(sorted(["a", "bb"], key=by_length))
+        threading.Thread(target=work).start()
+        "#,
+    );
+
+    assert_eq!(project.findings(), ["unused-function app.idle"]);
+}
+
+#[test]
+fn follows_class_members_read_in_the_class_body() {
+    let project = Project::new("class-members").file(
+        "app.py",
+        r#"
+        class Box:
+            def get_size(self):
+                return self.compute()
+
+            def compute(self):
+                return 1
+
+            size = property(get_size)
+
+
+        if __name__ == "__main__":
+            print(Box().size)
+        "#,
+    );
+
+    assert_eq!(project.findings(), Vec::<String>::new());
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn follows_a_break_in_the_else_of_a_nested_loop() {
+    let project = Project::new("nested-loop-break").file(
+        "app.py",
+        r#"
+        def first(items):
+            while True:
+                for item in items:
+                    pass
+                else:
+                    break
+            return 1
+
+
+        def second(items):
+            while True:
+                for item in items:
+                    break
+            return 2
+
+
+        first([])
+        second([])
+        "#,
+    );
+
+    assert_eq!(project.findings(), ["unreachable-code app.second"]);
+}
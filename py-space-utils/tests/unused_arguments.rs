@@ -0,0 +1,35 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn exempts_parameters_of_dunder_methods() {
+    let project = Project::new("dunder-arguments").file(
+        "app.py",
+        r#"
+        class Ghost:
+            def __eq__(self, other):
+                return True
+
+            def __getattr__(self, name):
+                return None
+
+            def __exit__(self, kind, value, traceback):
+                return False
+
+            def describe(self, verbose):
+                return "ghost"
+
+
+        ghost = Ghost()
+
+        with ghost:
+            print(ghost == ghost, ghost.name, ghost.describe(True))
+        "#,
+    );
+
+    assert_eq!(
+        project.findings(),
+        ["unused-argument app.Ghost.describe.verbose"]
+    );
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn reports_a_redefined_class_once() {
+    let project = Project::new("redefined-class").file(
+        "app.py",
+        r#"
+        try:
+            import json
+
+            class Encoder:
+                pass
+        except ImportError:
+            class Encoder:
+                pass
+
+        json.dumps({})
+        "#,
+    );
+
+    let report = project.check();
+    let positions: Vec<(&str, usize)> = report
+        .findings
+        .iter()
+        .map(|finding| (finding.qualified_name.as_str(), finding.line))
+        .collect();
+
+    assert_eq!(positions, [("app.Encoder", 4)]);
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn reports_a_redefined_function_once() {
+    let project = Project::new("redefined-function").file(
+        "app.py",
+        r#"
+        import sys
+
+        if sys.platform == "win32":
+            def helper():
+                return 1
+        else:
+            def helper():
+                return 2
+        "#,
+    );
+
+    let report = project.check();
+    let names: Vec<&str> = report
+        .findings
+        .iter()
+        .map(|finding| finding.qualified_name.as_str())
+        .collect();
+
+    assert_eq!(names, ["app.helper"]);
+    assert_eq!(report.findings[0].line, 4);
+}
+
+#[test]
+fn points_at_the_function_name_past_decorators() {
+    let project = Project::new("decorated-function").file(
+        "app.py",
+        r#"
+        import functools
+
+
+        @functools.lru_cache
+        def compute():
+            return 1
+
+
+        async def fetch():
+            return 2
+        "#,
+    );
+
+    let report = project.check();
+    let positions: Vec<(&str, usize, usize)> = report
+        .findings
+        .iter()
+        .map(|finding| {
+            (
+                finding.qualified_name.as_str(),
+                finding.line,
+                finding.column,
+            )
+        })
+        .collect();
+
+    assert_eq!(positions, [("app.compute", 5, 5), ("app.fetch", 9, 11)]);
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use common::Project;
+
+#[test]
+fn counts_reads_in_the_class_body_as_class_variable_reads() {
+    let project = Project::new("class-body-reads").file(
+        "app.py",
+        r#"
+        class Config:
+            base = 1
+            derived = base + 1
+            hidden = 2
+
+            def scale(self):
+                return hidden
+
+
+        print(Config.derived, Config().scale())
+        "#,
+    );
+
+    assert_eq!(project.findings(), ["unused-variable app.Config.hidden"]);
+}
+
+#[test]
+fn reads_names_in_subscript_and_call_result_callees() {
+    let project = Project::new("callee-reads").file(
+        "app.py",
+        r#"
+        def save():
+            return 1
+
+
+        HANDLERS = {"save": save}
+
+
+        def factory():
+            return save
+
+
+        def dispatch(key):
+            handlers = HANDLERS
+
+            return handlers[key](), factory()()
+
+
+        dispatch("save")
+        "#,
+    );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn keeps_a_variable_bound_in_place_of_a_failed_import() {
+    let project = Project::new("import-fallback").file(
+        "app.py",
+        r#"
+        try:
+            import ssl
+        except ImportError:
+            ssl = None
+
+
+        def connect():
+            if ssl is None:
+                return None
+
+            return ssl.create_default_context()
+
+
+        connect()
+        "#,
+    );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn keeps_a_nested_global_out_of_the_enclosing_locals() {
+    let project = Project::new("nested-global").file(
+        "app.py",
+        r#"
+        counter = 0
+
+
+        def outer():
+            def bump():
+                global counter
+                counter = 1
+
+            bump()
+
+
+        outer()
+        print(counter)
+        "#,
+    );
+
+    assert!(project.findings().is_empty());
+}
+
+#[test]
+fn reads_class_variables_overridden_in_subclasses() {
+    let project = Project::new("overridden-variable").file(
+        "app.py",
+        r#"
+        class Base:
+            timeout = 1
+            retries = 3
+
+            def wait(self):
+                return self.timeout
+
+
+        class Other(Base):
+            timeout = 5
+            retries = 0
+
+
+        Other().wait()
+        "#,
+    );
+
+    assert_eq!(
+        project.findings(),
+        [
+            "unused-variable app.Base.retries",
+            "unused-variable app.Other.retries"
+        ]
+    );
+}
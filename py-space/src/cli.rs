@@ -0,0 +1,302 @@
+use std::{num::NonZeroUsize, path::PathBuf};
+
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use py_space_utils::models::{
+    config::{Config, OutputFormat},
+    finding_kind::FindingKind,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "py-space",
+    version,
+    about = "Find unused code in Python projects",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    /// Log progress to stderr, -v for debug and -vv for trace output
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Format of the log lines written to stderr
+    #[arg(long, value_enum, default_value_t = LogFormat::Text, global = true)]
+    pub log_format: LogFormat,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub check: CheckArgs,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Analyze paths and report unused code (default)
+    Check(CheckArgs),
+
+    /// Export the call graph of the analyzed paths
+    Graph(GraphArgs),
+}
+
+/// Which files are analyzed and how, shared by every command.
+#[derive(Args)]
+pub struct ProjectArgs {
+    /// Files or directories to analyze, defaults to the current directory
+    pub paths: Vec<PathBuf>,
+
+    /// Read settings from this file instead of searching for pyproject.toml or
+    /// py-space.toml
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Directory reported paths are relative to, defaults to the directory of
+    /// the config file or the current directory
+    #[arg(long, value_name = "DIR")]
+    pub project_root: Option<PathBuf>,
+
+    /// Only analyze files matching this glob, can be repeated
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Skip files matching this glob, can be repeated
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Don't respect .gitignore and .ignore files while walking directories
+    #[arg(long)]
+    pub no_ignore: bool,
+
+    /// Number of threads used to parse files, defaults to the number of CPUs
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<NonZeroUsize>,
+}
+
+#[derive(Args)]
+pub struct CheckArgs {
+    #[command(flatten)]
+    pub project: ProjectArgs,
+
+    /// Only report findings of this kind, can be repeated [default: all]
+    #[arg(long, value_name = "KIND", value_parser = parse_kind)]
+    pub select: Vec<FindingKind>,
+
+    /// Hide findings with a confidence below this percentage [default: 0]
+    #[arg(long, value_name = "PERCENT", value_parser = clap::value_parser!(u8).range(0..=100))]
+    pub min_confidence: Option<u8>,
+
+    /// Output format of the report [default: text]
+    #[arg(long, value_enum)]
+    pub format: Option<Format>,
+
+    /// Exit with a failure when a finding kind is present or the number of
+    /// findings reaches a count, can be repeated [default: 1]
+    #[arg(long, value_name = "KIND|COUNT", value_parser = parse_fail_on)]
+    pub fail_on: Vec<FailOn>,
+}
+
+#[derive(Args)]
+pub struct GraphArgs {
+    #[command(flatten)]
+    pub project: ProjectArgs,
+
+    /// Output format of the graph
+    #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+    pub format: GraphFormat,
+
+    /// Only show what the modules, classes and functions under this dotted
+    /// prefix call
+    #[arg(long, value_name = "PREFIX")]
+    pub module: Option<String>,
+
+    /// Follow calls at most this many steps away [default: unlimited]
+    #[arg(long, value_name = "N")]
+    pub depth: Option<usize>,
+}
+
+#[derive(Clone)]
+pub enum FailOn {
+    Kind(FindingKind),
+    Count(usize),
+}
+
+fn get_kind_names() -> String {
+    let kinds: Vec<&str> = FindingKind::ALL.iter().map(|kind| kind.name()).collect();
+
+    kinds.join(", ")
+}
+
+fn parse_kind(value: &str) -> Result<FindingKind, String> {
+    match FindingKind::from_name(value) {
+        Some(kind) => Ok(kind),
+        None => Err(format!("expected one of: {}", get_kind_names())),
+    }
+}
+
+fn parse_fail_on(value: &str) -> Result<FailOn, String> {
+    if let Ok(count) = value.parse::<usize>() {
+        // Any run has at least zero findings, so 0 would always fail.
+        if count == 0 {
+            return Err("expected a count of at least 1".to_string());
+        }
+
+        return Ok(FailOn::Count(count));
+    }
+
+    match FindingKind::from_name(value) {
+        Some(kind) => Ok(FailOn::Kind(kind)),
+        None => Err(format!("expected a count or one of: {}", get_kind_names())),
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Sarif,
+}
+
+impl From<Format> for OutputFormat {
+    fn from(value: Format) -> Self {
+        match value {
+            Format::Text => OutputFormat::Text,
+            Format::Json => OutputFormat::Json,
+            Format::Sarif => OutputFormat::Sarif,
+        }
+    }
+}
+
+impl ProjectArgs {
+    /// Overrides values loaded from the config file with the flags that were
+    /// passed on the command line.
+    pub fn apply_to(self, config: &mut Config) {
+        if !self.paths.is_empty() {
+            config.paths = self.paths;
+        }
+
+        if self.project_root.is_some() {
+            config.project_root = self.project_root;
+        }
+
+        if !self.include.is_empty() {
+            config.include = self.include;
+        }
+
+        if !self.exclude.is_empty() {
+            config.exclude = self.exclude;
+        }
+
+        if self.no_ignore {
+            config.respect_ignore_files = false;
+        }
+
+        if let Some(jobs) = self.jobs {
+            config.jobs = Some(jobs.get());
+        }
+    }
+}
+
+impl CheckArgs {
+    /// Applies the project flags, then the finding kinds, minimum confidence
+    /// and format picked for the report. `--fail-on` only decides the exit
+    /// code, so it stays out of the config.
+    pub fn apply_to(self, config: &mut Config) {
+        self.project.apply_to(config);
+
+        if !self.select.is_empty() {
+            config.enabled_kinds = self.select;
+        }
+
+        if let Some(min_confidence) = self.min_confidence {
+            config.min_confidence = min_confidence;
+        }
+
+        if let Some(format) = self.format {
+            config.output_format = format.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fail_on_rejects_zero() {
+        assert!(parse_fail_on("0").is_err());
+        assert!(matches!(parse_fail_on("3"), Ok(FailOn::Count(3))));
+        assert!(matches!(
+            parse_fail_on("unused-import"),
+            Ok(FailOn::Kind(FindingKind::UnusedImport))
+        ));
+    }
+
+    fn build_config() -> Config {
+        let mut config = Config::new();
+
+        config.paths = vec![PathBuf::from("project/src")];
+        config.project_root = Some(PathBuf::from("project"));
+        config.exclude = vec!["src/migrations/**".to_string()];
+        config.min_confidence = 60;
+        config.enabled_kinds = vec![FindingKind::UnusedFunction];
+        config.output_format = OutputFormat::Json;
+
+        config
+    }
+
+    #[test]
+    fn flags_override_the_config_file() {
+        let cli = Cli::try_parse_from([
+            "py-space",
+            "--exclude",
+            "tests/**",
+            "--min-confidence",
+            "80",
+            "--select",
+            "unused-import",
+            "--format",
+            "sarif",
+            "--jobs",
+            "2",
+            "app",
+        ])
+        .unwrap();
+        let mut config = build_config();
+
+        cli.check.apply_to(&mut config);
+
+        assert_eq!(config.paths, [PathBuf::from("app")]);
+        assert_eq!(config.project_root, Some(PathBuf::from("project")));
+        assert_eq!(config.exclude, ["tests/**"]);
+        assert_eq!(config.min_confidence, 80);
+        assert_eq!(config.enabled_kinds, [FindingKind::UnusedImport]);
+        assert_eq!(config.output_format, OutputFormat::Sarif);
+        assert_eq!(config.jobs, Some(2));
+    }
+
+    #[test]
+    fn keeps_the_config_file_values_without_flags() {
+        let cli = Cli::try_parse_from(["py-space"]).unwrap();
+        let mut config = build_config();
+
+        cli.check.apply_to(&mut config);
+
+        assert_eq!(config.paths, [PathBuf::from("project/src")]);
+        assert_eq!(config.exclude, ["src/migrations/**"]);
+        assert_eq!(config.min_confidence, 60);
+        assert_eq!(config.enabled_kinds, [FindingKind::UnusedFunction]);
+        assert_eq!(config.output_format, OutputFormat::Json);
+        assert_eq!(config.jobs, None);
+    }
+}
@@ -0,0 +1,53 @@
+//! Graphviz output, render it with `dot -Tsvg graph.dot -o graph.svg`.
+//!
+//! ```dot
+//! digraph calls {
+//!     rankdir=LR;
+//!     "app.main" [shape=box];
+//!     "app.main.run" [shape=ellipse];
+//!     "app.main" -> "app.main.run";
+//! }
+//! ```
+
+use std::io::{self, Write};
+
+use py_space_utils::models::graph::{Graph, GraphNodeKind};
+
+use super::GraphWriter;
+
+fn get_shape(kind: GraphNodeKind) -> &'static str {
+    match kind {
+        GraphNodeKind::Module => "box",
+        GraphNodeKind::Class => "component",
+        GraphNodeKind::Function => "ellipse",
+        GraphNodeKind::Method => "oval",
+    }
+}
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub struct DotWriter;
+
+impl GraphWriter for DotWriter {
+    fn write(&self, graph: &Graph, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "digraph calls {{")?;
+        writeln!(writer, "    rankdir=LR;")?;
+
+        for (name, node) in &graph.nodes {
+            writeln!(
+                writer,
+                "    {} [shape={}];",
+                quote(name),
+                get_shape(node.kind)
+            )?;
+        }
+
+        for (caller, callee) in &graph.edges {
+            writeln!(writer, "    {} -> {};", quote(caller), quote(callee))?;
+        }
+
+        writeln!(writer, "}}")
+    }
+}
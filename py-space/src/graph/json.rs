@@ -0,0 +1,71 @@
+//! JSON call graph, schema version 1.
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "nodes": [
+//!     { "name": "app.main.run", "kind": "function", "file": "app/main.py", "line": 4 }
+//!   ],
+//!   "edges": [
+//!     { "caller": "app.main", "callee": "app.main.run" }
+//!   ]
+//! }
+//! ```
+
+use std::io::{self, Write};
+
+use py_space_utils::models::graph::Graph;
+use serde::Serialize;
+
+use super::GraphWriter;
+
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct JsonGraph<'a> {
+    schema_version: u32,
+    nodes: Vec<JsonNode<'a>>,
+    edges: Vec<JsonEdge<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonNode<'a> {
+    name: &'a str,
+    kind: &'static str,
+    file: String,
+    line: usize,
+}
+
+#[derive(Serialize)]
+struct JsonEdge<'a> {
+    caller: &'a str,
+    callee: &'a str,
+}
+
+pub struct JsonWriter;
+
+impl GraphWriter for JsonWriter {
+    fn write(&self, graph: &Graph, writer: &mut dyn Write) -> io::Result<()> {
+        let json_graph = JsonGraph {
+            schema_version: SCHEMA_VERSION,
+            nodes: graph
+                .nodes
+                .iter()
+                .map(|(name, node)| JsonNode {
+                    name,
+                    kind: node.kind.name(),
+                    file: node.file.to_string_lossy().to_string(),
+                    line: node.line,
+                })
+                .collect(),
+            edges: graph
+                .edges
+                .iter()
+                .map(|(caller, callee)| JsonEdge { caller, callee })
+                .collect(),
+        };
+
+        serde_json::to_writer_pretty(&mut *writer, &json_graph)?;
+        writeln!(writer)
+    }
+}
@@ -0,0 +1,19 @@
+mod dot;
+mod json;
+
+use std::io::{self, Write};
+
+use py_space_utils::models::graph::Graph;
+
+use crate::cli::GraphFormat;
+
+pub trait GraphWriter {
+    fn write(&self, graph: &Graph, writer: &mut dyn Write) -> io::Result<()>;
+}
+
+pub fn get_graph_writer(format: GraphFormat) -> Box<dyn GraphWriter> {
+    match format {
+        GraphFormat::Dot => Box::new(dot::DotWriter),
+        GraphFormat::Json => Box::new(json::JsonWriter),
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    io::{self, Write},
+    time::Instant,
+};
+
+use log::{LevelFilter, Log, Metadata, Record};
+use serde_json::json;
+
+use crate::cli::LogFormat;
+
+const LOG_TARGET_PREFIX: &str = "py_space";
+
+struct Logger {
+    format: LogFormat,
+    start_time: Instant,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level() && metadata.target().starts_with(LOG_TARGET_PREFIX)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let elapsed_ms = self.start_time.elapsed().as_millis();
+        let mut stderr = io::stderr().lock();
+
+        let _ = match self.format {
+            LogFormat::Text => writeln!(
+                stderr,
+                "[{:>6}ms {:<5} {}] {}",
+                elapsed_ms,
+                record.level(),
+                record.target(),
+                record.args()
+            ),
+            LogFormat::Json => writeln!(
+                stderr,
+                "{}",
+                json!({
+                    "elapsed_ms": elapsed_ms,
+                    "level": record.level().as_str(),
+                    "target": record.target(),
+                    "message": record.args().to_string(),
+                })
+            ),
+        };
+    }
+
+    fn flush(&self) {
+        let _ = io::stderr().flush();
+    }
+}
+
+fn get_level_filter(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+pub fn init_logging(verbosity: u8, format: LogFormat) {
+    let logger = Logger {
+        format,
+        start_time: Instant::now(),
+    };
+
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(get_level_filter(verbosity));
+    }
+}
@@ -1,10 +1,125 @@
-use py_space_utils::analyzer::analyze_project;
+mod cli;
+mod graph;
+mod logging;
+mod reporters;
 
-fn main() {
-    let result = analyze_project();
+use std::{io, process::ExitCode};
 
-    match result {
-        Ok(_value) => print!("success"),
-        Err(error) => print!("{}", error),
+use clap::Parser;
+use cli::{CheckArgs, Cli, Command, FailOn, GraphArgs, ProjectArgs};
+use graph::get_graph_writer;
+use logging::init_logging;
+use py_space_utils::{
+    analyzer::{analyze_call_graph, analyze_project},
+    config_file::load_config,
+    errors::py_space::PySpaceError,
+    models::{config::Config, report::Report},
+};
+use reporters::get_reporter;
+
+const EXIT_CLEAN: u8 = 0;
+const EXIT_FINDINGS: u8 = 1;
+const EXIT_ERROR: u8 = 2;
+
+fn should_fail(fail_on: &[FailOn], report: &Report) -> bool {
+    let findings_count = report.count_by_kind();
+    let total = report.findings.len();
+
+    if fail_on.is_empty() {
+        return total > 0;
+    }
+
+    fail_on.iter().any(|threshold| match threshold {
+        FailOn::Kind(kind) => findings_count.get(kind).is_some_and(|count| *count > 0),
+        FailOn::Count(count) => total >= *count,
+    })
+}
+
+fn load_project_config(project_args: &ProjectArgs) -> Result<Config, PySpaceError> {
+    load_config(
+        project_args.config.as_deref(),
+        project_args.project_root.as_deref(),
+    )
+}
+
+fn run_check(check_args: CheckArgs) -> ExitCode {
+    let fail_on = check_args.fail_on.clone();
+
+    let mut config = match load_project_config(&check_args.project) {
+        Ok(value) => value,
+        Err(error) => {
+            eprintln!("{}", error);
+
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+
+    check_args.apply_to(&mut config);
+
+    let report = match analyze_project(&config) {
+        Ok(value) => value,
+        Err(error) => {
+            eprintln!("{}", error);
+
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+
+    let reporter = get_reporter(config.output_format);
+
+    if let Err(error) = reporter.report(&report, &mut io::stdout().lock()) {
+        eprintln!("Failed to write report: {}", error);
+
+        return ExitCode::from(EXIT_ERROR);
+    }
+
+    if should_fail(&fail_on, &report) {
+        ExitCode::from(EXIT_FINDINGS)
+    } else {
+        ExitCode::from(EXIT_CLEAN)
+    }
+}
+
+fn run_graph(graph_args: GraphArgs) -> ExitCode {
+    let mut config = match load_project_config(&graph_args.project) {
+        Ok(value) => value,
+        Err(error) => {
+            eprintln!("{}", error);
+
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+
+    graph_args.project.apply_to(&mut config);
+
+    let graph = match analyze_call_graph(&config) {
+        Ok(value) => value.filter(graph_args.module.as_deref(), graph_args.depth),
+        Err(error) => {
+            eprintln!("{}", error);
+
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+
+    let graph_writer = get_graph_writer(graph_args.format);
+
+    if let Err(error) = graph_writer.write(&graph, &mut io::stdout().lock()) {
+        eprintln!("Failed to write graph: {}", error);
+
+        return ExitCode::from(EXIT_ERROR);
+    }
+
+    ExitCode::from(EXIT_CLEAN)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    init_logging(cli.verbose, cli.log_format);
+
+    match cli.command {
+        Some(Command::Check(value)) => run_check(value),
+        Some(Command::Graph(value)) => run_graph(value),
+        None => run_check(cli.check),
     }
 }
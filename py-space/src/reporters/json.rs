@@ -0,0 +1,107 @@
+//! JSON report, schema version 1.
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "tool": { "name": "py-space", "version": "0.1.0" },
+//!   "run": { "files_scanned": 3, "files_failed": 0, "duration_ms": 10 },
+//!   "findings": [
+//!     {
+//!       "kind": "unused-function",
+//!       "qualified_name": "tests.unused.second",
+//!       "file": "tests/unused.py",
+//!       "line": 10,
+//!       "column": 5,
+//!       "end_line": 11,
+//!       "end_column": 20,
+//!       "confidence": 60,
+//!       "reason": "function is never called"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Fields are only ever added within a schema version, removing or changing
+//! the meaning of a field bumps `schema_version`.
+
+use std::io::{self, Write};
+
+use py_space_utils::models::{finding::Finding, report::Report};
+use serde::Serialize;
+
+use super::Reporter;
+
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    schema_version: u32,
+    tool: JsonTool,
+    run: JsonRun,
+    findings: Vec<JsonFinding<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonTool {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct JsonRun {
+    files_scanned: usize,
+    files_failed: usize,
+    duration_ms: u128,
+}
+
+#[derive(Serialize)]
+struct JsonFinding<'a> {
+    kind: &'static str,
+    qualified_name: &'a str,
+    file: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    confidence: u8,
+    reason: &'a str,
+}
+
+impl<'a> From<&'a Finding> for JsonFinding<'a> {
+    fn from(finding: &'a Finding) -> Self {
+        Self {
+            kind: finding.kind.name(),
+            qualified_name: &finding.qualified_name,
+            file: finding.file.to_string_lossy().to_string(),
+            line: finding.line,
+            column: finding.column,
+            end_line: finding.end_line,
+            end_column: finding.end_column,
+            confidence: finding.confidence,
+            reason: &finding.reason,
+        }
+    }
+}
+
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn report(&self, report: &Report, writer: &mut dyn Write) -> io::Result<()> {
+        let json_report = JsonReport {
+            schema_version: SCHEMA_VERSION,
+            tool: JsonTool {
+                name: env!("CARGO_PKG_NAME"),
+                version: env!("CARGO_PKG_VERSION"),
+            },
+            run: JsonRun {
+                files_scanned: report.files_scanned,
+                files_failed: report.files_failed,
+                duration_ms: report.duration.as_millis(),
+            },
+            findings: report.findings.iter().map(JsonFinding::from).collect(),
+        };
+
+        serde_json::to_writer_pretty(&mut *writer, &json_report)?;
+        writeln!(writer)
+    }
+}
@@ -0,0 +1,19 @@
+mod json;
+mod sarif;
+mod text;
+
+use std::io::{self, Write};
+
+use py_space_utils::models::{config::OutputFormat, report::Report};
+
+pub trait Reporter {
+    fn report(&self, report: &Report, writer: &mut dyn Write) -> io::Result<()>;
+}
+
+pub fn get_reporter(format: OutputFormat) -> Box<dyn Reporter> {
+    match format {
+        OutputFormat::Text => Box::new(text::TextReporter),
+        OutputFormat::Json => Box::new(json::JsonReporter),
+        OutputFormat::Sarif => Box::new(sarif::SarifReporter),
+    }
+}
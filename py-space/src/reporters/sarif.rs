@@ -0,0 +1,172 @@
+use std::io::{self, Write};
+
+use py_space_utils::models::{finding::Finding, finding_kind::FindingKind, report::Report};
+use serde_json::{Value, json};
+
+use super::Reporter;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+fn build_rule(kind: FindingKind) -> Value {
+    json!({
+        "id": kind.rule_id(),
+        "name": kind.name(),
+        "shortDescription": { "text": kind.description() },
+        "fullDescription": { "text": kind.help() },
+        "help": { "text": kind.help() },
+        "defaultConfiguration": { "level": "warning" },
+    })
+}
+
+fn build_result(finding: &Finding) -> Value {
+    let rule_index = FindingKind::ALL
+        .iter()
+        .position(|kind| *kind == finding.kind)
+        .expect("FindingKind::ALL lists every finding kind");
+
+    let uri = finding.file.to_string_lossy().replace('\\', "/");
+
+    json!({
+        "ruleId": finding.kind.rule_id(),
+        "ruleIndex": rule_index,
+        "level": "warning",
+        "message": {
+            "text": format!("`{}`: {}", finding.qualified_name, finding.reason),
+        },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": {
+                    "uri": uri,
+                    "uriBaseId": "%SRCROOT%",
+                },
+                "region": {
+                    "startLine": finding.line,
+                    "startColumn": finding.column,
+                    "endLine": finding.end_line,
+                    "endColumn": finding.end_column,
+                },
+            },
+            "logicalLocations": [{
+                "fullyQualifiedName": finding.qualified_name,
+            }],
+        }],
+        "properties": {
+            "confidence": finding.confidence,
+        },
+    })
+}
+
+pub struct SarifReporter;
+
+impl Reporter for SarifReporter {
+    fn report(&self, report: &Report, writer: &mut dyn Write) -> io::Result<()> {
+        let rules: Vec<Value> = FindingKind::ALL.into_iter().map(build_rule).collect();
+        let results: Vec<Value> = report.findings.iter().map(build_result).collect();
+
+        let sarif = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": env!("CARGO_PKG_NAME"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "columnKind": "unicodeCodePoints",
+                "results": results,
+                "invocations": [{
+                    "executionSuccessful": true,
+                    "properties": {
+                        "filesScanned": report.files_scanned,
+                        "filesFailed": report.files_failed,
+                    },
+                }],
+            }],
+        });
+
+        serde_json::to_writer_pretty(&mut *writer, &sarif)?;
+        writeln!(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use py_space_utils::models::location::Location;
+
+    use super::*;
+
+    fn render(report: &Report) -> Value {
+        let mut output = Vec::new();
+
+        SarifReporter.report(report, &mut output).unwrap();
+
+        serde_json::from_slice(&output).unwrap()
+    }
+
+    #[test]
+    fn reports_findings_as_sarif_results() {
+        let mut report = Report::new();
+        report.files_scanned = 2;
+        report.findings.push(Finding::new(
+            FindingKind::UnusedMethod,
+            "app.models.User.save".to_string(),
+            PathBuf::from("app\\models.py"),
+            Location::new(4, 5),
+            Location::new(5, 17),
+            90,
+            "never called".to_string(),
+        ));
+
+        let sarif = render(&report);
+        let run = &sarif["runs"][0];
+
+        assert_eq!(sarif["version"], SARIF_VERSION);
+        assert_eq!(sarif["$schema"], SARIF_SCHEMA);
+
+        let rule_ids: Vec<&str> = run["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|rule| rule["id"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(
+            rule_ids,
+            [
+                "PS001", "PS002", "PS003", "PS004", "PS005", "PS006", "PS007", "PS008"
+            ]
+        );
+
+        let result = &run["results"][0];
+        let location = &result["locations"][0];
+
+        assert_eq!(result["ruleId"], "PS002");
+        assert_eq!(result["ruleIndex"], 1);
+        assert_eq!(
+            result["message"]["text"],
+            "`app.models.User.save`: never called"
+        );
+        assert_eq!(
+            location["physicalLocation"]["artifactLocation"],
+            json!({ "uri": "app/models.py", "uriBaseId": "%SRCROOT%" })
+        );
+        assert_eq!(
+            location["physicalLocation"]["region"],
+            json!({ "startLine": 4, "startColumn": 5, "endLine": 5, "endColumn": 17 })
+        );
+        assert_eq!(
+            location["logicalLocations"][0]["fullyQualifiedName"],
+            "app.models.User.save"
+        );
+        assert_eq!(result["properties"]["confidence"], 90);
+        assert_eq!(
+            run["invocations"][0]["properties"],
+            json!({ "filesScanned": 2, "filesFailed": 0 })
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use std::io::{self, Write};
+
+use py_space_utils::models::report::Report;
+
+use super::Reporter;
+
+pub struct TextReporter;
+
+impl Reporter for TextReporter {
+    fn report(&self, report: &Report, writer: &mut dyn Write) -> io::Result<()> {
+        for finding in &report.findings {
+            writeln!(
+                writer,
+                "{}:{}:{}: {} `{}` ({}, {}% confidence)",
+                finding.file.display(),
+                finding.line,
+                finding.column,
+                finding.kind,
+                finding.qualified_name,
+                finding.reason,
+                finding.confidence
+            )?;
+        }
+
+        Ok(())
+    }
+}